@@ -1,20 +1,16 @@
-use modrinth_api::structs::search::{ExtendedSearch, Sort};
+use modrinth_api::structs::search::{SearchQuery, Sort};
 use modrinth_api::{Error, ModrinthAPI};
 
 #[tokio::main]
 async fn main() -> Result<(), Error> {
     let api = ModrinthAPI::default();
-    let result = api
-        .search(
-            "xaeros",         // Query
-            &Sort::Downloads, // Sort
-            Some(20),         // Maximum number of results to return.
-            Some(ExtendedSearch {
-                offset: None,   // The offset into the search. Skips this number of results
-                facets: vec![], // Facets are an essential concept for understanding how to filter out results.
-            }),
-        )
-        .await?;
+    let query = SearchQuery::new("xaeros") // Query
+        .sort(Sort::Downloads) // Sort
+        .limit(20) // Maximum number of results to return.
+        .offset(0); // The offset into the search. Skips this number of results
+    // Facets are an essential concept for understanding how to filter out results,
+    // see `SearchQuery::facet_and` and `SearchQuery::facet_or`.
+    let result = api.search_with(&query).await?;
 
     println!("{:#?}", result);
     Ok(())
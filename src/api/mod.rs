@@ -1,5 +1,14 @@
+pub mod notifications;
 pub mod projects;
+pub mod reports;
 pub mod search;
+pub mod tags;
+pub mod teams;
+pub mod threads;
+pub mod users;
 pub mod versions;
 
-use crate::{BASE_URL, ModrinthAPI, Result};
+#[cfg(feature = "v3")]
+pub mod v3;
+
+use crate::{ModrinthAPI, Result};
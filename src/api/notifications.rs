@@ -0,0 +1,152 @@
+//! API functions related to notifications
+//!
+//! All of these require a client created with [ModrinthAPI::with_token].
+
+use super::*;
+use crate::{
+    structs::{
+        ids::{NotificationId, UserRef},
+        notifications::Notification,
+    },
+    utils::{RequestBuilderCustomSend, UrlJoinAll, UrlWithQuery, check_id_slug},
+};
+
+impl ModrinthAPI {
+    /// Get the notifications of a user
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The ID or username of the user ([`UserRef`], e.g. `&str` or [`UserId`](crate::structs::ids::UserId))
+    ///
+    /// # Returns
+    ///
+    /// `Result<Vec<Notification>>`:
+    /// - `Ok(Vec<Notification>)`: A list of the user's [`Notification`] structs.
+    /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
+    pub async fn get_user_notifications(
+        &self,
+        user: impl Into<UserRef>,
+    ) -> Result<Vec<Notification>> {
+        let user = user.into();
+        check_id_slug(&[user.as_str()])?;
+        self.client
+            .get(
+                self.base_url
+                    .join_all(vec!["user", user.as_str(), "notifications"]),
+            )
+            .custom_send_json()
+            .await
+    }
+
+    /// Get a notification by its ID
+    ///
+    /// # Arguments
+    ///
+    /// * `notification_id` - The ID of the notification ([`NotificationId`])
+    ///
+    /// # Returns
+    ///
+    /// `Result<Notification>`:
+    /// - `Ok(Notification)`: [`Notification`] struct.
+    /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
+    pub async fn get_notification(&self, notification_id: &NotificationId) -> Result<Notification> {
+        self.client
+            .get(
+                self.base_url
+                    .join_all(vec!["notification", notification_id.as_str()]),
+            )
+            .custom_send_json()
+            .await
+    }
+
+    /// Get multiple notifications by their IDs
+    ///
+    /// # Arguments
+    ///
+    /// * `notification_ids` - The IDs of the notifications (`&[NotificationId]`)
+    ///
+    /// # Returns
+    ///
+    /// `Result<Vec<Notification>>`:
+    /// - `Ok(Vec<Notification>)`: A list of the [`Notification`] structs.
+    /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
+    pub async fn get_notifications(
+        &self,
+        notification_ids: &[NotificationId],
+    ) -> Result<Vec<Notification>> {
+        self.client
+            .get(
+                self.base_url
+                    .join_all(vec!["notifications"])
+                    .with_query_json("ids", notification_ids)?,
+            )
+            .custom_send_json()
+            .await
+    }
+
+    /// Mark a notification as read
+    ///
+    /// # Arguments
+    ///
+    /// * `notification_id` - The ID of the notification ([`NotificationId`])
+    pub async fn mark_notification_read(&self, notification_id: &NotificationId) -> Result<()> {
+        self.client
+            .patch(
+                self.base_url
+                    .join_all(vec!["notification", notification_id.as_str()]),
+            )
+            .custom_send()
+            .await?;
+        Ok(())
+    }
+
+    /// Mark multiple notifications as read
+    ///
+    /// # Arguments
+    ///
+    /// * `notification_ids` - The IDs of the notifications (`&[NotificationId]`)
+    pub async fn mark_notifications_read(&self, notification_ids: &[NotificationId]) -> Result<()> {
+        self.client
+            .patch(
+                self.base_url
+                    .join_all(vec!["notifications"])
+                    .with_query_json("ids", notification_ids)?,
+            )
+            .custom_send()
+            .await?;
+        Ok(())
+    }
+
+    /// Delete a notification
+    ///
+    /// # Arguments
+    ///
+    /// * `notification_id` - The ID of the notification ([`NotificationId`])
+    pub async fn delete_notification(&self, notification_id: &NotificationId) -> Result<()> {
+        self.client
+            .delete(
+                self.base_url
+                    .join_all(vec!["notification", notification_id.as_str()]),
+            )
+            .custom_send()
+            .await?;
+        Ok(())
+    }
+
+    /// Delete multiple notifications
+    ///
+    /// # Arguments
+    ///
+    /// * `notification_ids` - The IDs of the notifications (`&[NotificationId]`)
+    pub async fn delete_notifications(&self, notification_ids: &[NotificationId]) -> Result<()> {
+        self.client
+            .delete(
+                self.base_url
+                    .join_all(vec!["notifications"])
+                    .with_query_json("ids", notification_ids)?,
+            )
+            .custom_send()
+            .await?;
+        Ok(())
+    }
+}
@@ -1,28 +1,172 @@
-//! API functions to get project by id/slug
+//! API functions to get and follow projects by id/slug
 
 use super::*;
 use crate::{
-    structs::projects::Project,
+    structs::{
+        ids::{ProjectId, ProjectRef},
+        projects::Project,
+    },
     utils::{RequestBuilderCustomSend, UrlJoinAll, check_id_slug},
 };
+use serde::Deserialize;
+use std::sync::PoisonError;
 
 impl ModrinthAPI {
     /// Get information about Project ([Project] struct) by project slug (id)
     ///
     /// # Arguments
     ///
-    /// * `project_id` - A project slug/id (`&str`)
+    /// * `project_id` - A project slug/id ([`ProjectRef`], e.g. `&str` or [`ProjectId`])
     ///
     /// # Returns
     ///
     /// `Result<Project>`:
     /// - `Ok(Project)`: The [`Project`] data.
     /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
-    pub async fn get_project_by_id(&self, project_id: &str) -> Result<Project> {
-        check_id_slug(&[project_id])?;
-        self.client
-            .get(BASE_URL.join_all(vec!["project", project_id]))
+    pub async fn get_project_by_id(&self, project_id: impl Into<ProjectRef>) -> Result<Project> {
+        let project_id = project_id.into();
+        check_id_slug(&[project_id.as_str()])?;
+        let project: Project = self
+            .client
+            .get(self.base_url.join_all(vec!["project", project_id.as_str()]))
+            .custom_send_json()
+            .await?;
+        self.cache_project_id(&project.slug, &project.id);
+        self.cache_project_id(project.id.as_str(), &project.id);
+        Ok(project)
+    }
+
+    /// Resolve a project slug (or an ID given as a string) to its ID
+    ///
+    /// A typed [`ProjectId`] is returned as is. Strings are checked with the API once and then
+    /// cached in this client (and its clones), slugs of projects fetched with
+    /// [ModrinthAPI::get_project_by_id] are cached too.
+    ///
+    /// Cached entries are never evicted, so a slug that is renamed (or taken by another project)
+    /// keeps resolving to the old ID until it is removed with [ModrinthAPI::forget_project_id]
+    /// or [ModrinthAPI::clear_project_id_cache].
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - A project slug/id ([`ProjectRef`], e.g. `&str` or [`ProjectId`])
+    ///
+    /// # Returns
+    ///
+    /// `Result<ProjectId>`:
+    /// - `Ok(ProjectId)`: The ID of the project.
+    /// - `Err(crate::error::Error)`: An error occurred during the API request (e.g. unknown slug).
+    pub async fn resolve_project_id(&self, project: impl Into<ProjectRef>) -> Result<ProjectId> {
+        let slug = match project.into() {
+            ProjectRef::Id(id) => return Ok(id),
+            ProjectRef::IdOrSlug(id_or_slug) => id_or_slug,
+        };
+        check_id_slug(&[&slug])?;
+
+        let cached = self
+            .project_ids
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&project_cache_key(&slug))
+            .cloned();
+        if let Some(id) = cached {
+            return Ok(id);
+        }
+
+        #[derive(Deserialize)]
+        struct Check {
+            id: ProjectId,
+        }
+        let Check { id } = self
+            .client
+            .get(self.base_url.join_all(vec!["project", &slug, "check"]))
             .custom_send_json()
-            .await
+            .await?;
+        self.cache_project_id(&slug, &id);
+        Ok(id)
+    }
+
+    fn cache_project_id(&self, slug: &str, id: &ProjectId) {
+        self.project_ids
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(project_cache_key(slug), id.clone());
+    }
+
+    /// Remove a slug (or ID) from the cache of [ModrinthAPI::resolve_project_id],
+    /// e.g. after the project was renamed
+    pub fn forget_project_id(&self, id_or_slug: &str) {
+        self.project_ids
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&project_cache_key(id_or_slug));
+    }
+
+    /// Remove all entries from the cache of [ModrinthAPI::resolve_project_id]
+    pub fn clear_project_id_cache(&self) {
+        self.project_ids
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+
+    /// Follow a project as the authenticated user
+    ///
+    /// Requires a client created with [ModrinthAPI::with_token].
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - A project slug/id ([`ProjectRef`], e.g. `&str` or [`ProjectId`])
+    ///
+    /// # Returns
+    ///
+    /// `Result<()>`:
+    /// - `Ok(())`: The project is now followed.
+    /// - `Err(crate::error::Error)`: An error occurred during the API request (e.g. missing token).
+    pub async fn follow_project(&self, project_id: impl Into<ProjectRef>) -> Result<()> {
+        let project_id = project_id.into();
+        check_id_slug(&[project_id.as_str()])?;
+        self.client
+            .post(
+                self.base_url
+                    .join_all(vec!["project", project_id.as_str(), "follow"]),
+            )
+            .custom_send()
+            .await?;
+        Ok(())
+    }
+
+    /// Unfollow a project as the authenticated user
+    ///
+    /// Requires a client created with [ModrinthAPI::with_token].
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - A project slug/id ([`ProjectRef`], e.g. `&str` or [`ProjectId`])
+    ///
+    /// # Returns
+    ///
+    /// `Result<()>`:
+    /// - `Ok(())`: The project is no longer followed.
+    /// - `Err(crate::error::Error)`: An error occurred during the API request (e.g. missing token).
+    pub async fn unfollow_project(&self, project_id: impl Into<ProjectRef>) -> Result<()> {
+        let project_id = project_id.into();
+        check_id_slug(&[project_id.as_str()])?;
+        self.client
+            .delete(
+                self.base_url
+                    .join_all(vec!["project", project_id.as_str(), "follow"]),
+            )
+            .custom_send()
+            .await?;
+        Ok(())
+    }
+}
+
+/// Slugs are case-insensitive, but IDs are not, so strings that could be an ID are kept as is
+fn project_cache_key(id_or_slug: &str) -> String {
+    if id_or_slug.parse::<ProjectId>().is_ok() {
+        id_or_slug.to_string()
+    } else {
+        id_or_slug.to_lowercase()
     }
 }
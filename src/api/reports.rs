@@ -0,0 +1,108 @@
+//! API functions related to reports
+//!
+//! All of these require a client created with [ModrinthAPI::with_token].
+
+use super::*;
+use crate::{
+    structs::{
+        ids::ReportId,
+        reports::{CreateReport, EditReport, Report},
+    },
+    utils::{RequestBuilderCustomSend, UrlJoinAll, UrlWithQuery, check_id_slug},
+};
+
+impl ModrinthAPI {
+    /// Report a project, version, or user
+    ///
+    /// # Arguments
+    ///
+    /// * `report` - The report to submit ([`CreateReport`])
+    ///
+    /// # Returns
+    ///
+    /// `Result<Report>`:
+    /// - `Ok(Report)`: The created [`Report`] struct.
+    /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
+    pub async fn create_report(&self, report: &CreateReport) -> Result<Report> {
+        check_id_slug(&[&report.item_id])?;
+        self.client
+            .post(self.base_url.join_all(vec!["report"]))
+            .json(report)
+            .custom_send_json()
+            .await
+    }
+
+    /// Get the reports submitted by the authenticated user
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - An optional maximum number of reports to return
+    ///
+    /// # Returns
+    ///
+    /// `Result<Vec<Report>>`:
+    /// - `Ok(Vec<Report>)`: A list of the [`Report`] structs.
+    /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
+    pub async fn get_user_reports(&self, count: Option<u32>) -> Result<Vec<Report>> {
+        let mut url = self.base_url.join_all(vec!["report"]);
+        if let Some(count) = count {
+            url = url.with_query("count", count);
+        }
+        self.client.get(url).custom_send_json().await
+    }
+
+    /// Get a report by its ID
+    ///
+    /// # Arguments
+    ///
+    /// * `report_id` - The ID of the report ([`ReportId`])
+    ///
+    /// # Returns
+    ///
+    /// `Result<Report>`:
+    /// - `Ok(Report)`: [`Report`] struct.
+    /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
+    pub async fn get_report(&self, report_id: &ReportId) -> Result<Report> {
+        self.client
+            .get(self.base_url.join_all(vec!["report", report_id.as_str()]))
+            .custom_send_json()
+            .await
+    }
+
+    /// Get multiple reports by their IDs
+    ///
+    /// # Arguments
+    ///
+    /// * `report_ids` - The IDs of the reports (`&[ReportId]`)
+    ///
+    /// # Returns
+    ///
+    /// `Result<Vec<Report>>`:
+    /// - `Ok(Vec<Report>)`: A list of the [`Report`] structs.
+    /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
+    pub async fn get_reports(&self, report_ids: &[ReportId]) -> Result<Vec<Report>> {
+        self.client
+            .get(
+                self.base_url
+                    .join_all(vec!["reports"])
+                    .with_query_json("ids", report_ids)?,
+            )
+            .custom_send_json()
+            .await
+    }
+
+    /// Edit a report
+    ///
+    /// # Arguments
+    ///
+    /// * `report_id` - The ID of the report ([`ReportId`])
+    /// * `edit` - The fields to change ([`EditReport`])
+    pub async fn edit_report(&self, report_id: &ReportId, edit: &EditReport) -> Result<()> {
+        self.client
+            .patch(self.base_url.join_all(vec!["report", report_id.as_str()]))
+            .json(edit)
+            .custom_send()
+            .await?;
+        Ok(())
+    }
+}
@@ -1,39 +1,30 @@
 //! API functions to search items by query
 
 use super::*;
-use crate::structs::search::{ExtendedSearch, Response, Sort};
-use crate::utils::{RequestBuilderCustomSend, UrlJoinAll, UrlWithQuery};
+use crate::Error;
+use crate::structs::search::{ExtendedSearch, Facet, Response, SearchHit, SearchQuery, Sort};
+use crate::utils::RequestBuilderCustomSend;
+use futures_util::{Stream, StreamExt, TryStreamExt, stream};
 
 impl ModrinthAPI {
-    /// Performs an extended search for projects on Modrinth, allowing for more granular control over the search
-    /// results through various filtering options.
-    ///
-    /// This function builds upon the basic search by introducing `offset` and `facets`
-    /// for more refined queries.
+    /// Searches for projects on Modrinth using a [SearchQuery].
     ///
     /// # Arguments
     ///
-    /// * `query` - The search query string.
-    /// * `sort` - The sorting criteria for the search results (e.g., by downloads, recency).
-    /// * `limit` - An optional maximum number of results to return. Defaults to 20 if `None`.
-    /// * `extended_search` - A struct containing additional search parameters:
-    ///     * `offset` - An optional starting point for the results. Defaults to 20 if `None`.
-    ///     * `facets` - A vector of vectors of `Facet` enums, allowing for complex filtering.
-    ///       Each inner vector represents a group of facets, where results must match
-    ///       at least one facet from each inner group.
+    /// * `query` - The [`SearchQuery`] containing the query string, sort, limit, offset and facets.
     ///
     /// # Returns
     ///
     /// A `Result` which is:
     /// * `Ok(Response)` - On successful retrieval of search results. The `Response` struct
     ///   contains a list of `Hit` objects representing the found projects.
-    /// * `Err(Error)` - If an error occurs during the API call or data processing.
+    /// * `Err(Error)` - If the limit is out of range, or an error occurs during the API call or data processing.
     ///
     /// # Examples
     ///
     /// ```no_run
     /// use modrinth_api::{
-    ///     ModrinthAPI, structs::search::{Sort, ExtendedSearch, Facet, Response}
+    ///     ModrinthAPI, structs::search::{Sort, Facet, SearchQuery}
     /// };
     ///
     /// #[tokio::main]
@@ -42,20 +33,13 @@ impl ModrinthAPI {
     ///
     ///     // Example: Search for "fabric" mods, sorted by downloads,
     ///     // and filter for projects compatible with "1.19.2" and "Forge" loader.
-    ///     let extended_search_params = ExtendedSearch {
-    ///         offset: Some(0),
-    ///         facets: vec![
-    ///             vec![Facet::Versions("1.19.2".to_string())],
-    ///             vec![Facet::Categories("fabric".to_string())],
-    ///         ],
-    ///     };
-    ///
-    ///     let response: Response = api.extended_search(
-    ///         "fabric",
-    ///         &Sort::Downloads,
-    ///         Some(10),
-    ///         Some(extended_search_params),
-    ///     ).await?;
+    ///     let query = SearchQuery::new("fabric")
+    ///         .sort(Sort::Downloads)
+    ///         .limit(10)
+    ///         .facet_and(Facet::Versions("1.19.2".to_string()))
+    ///         .facet_and(Facet::Categories("forge".to_string()));
+    ///
+    ///     let response = api.search_with(&query).await?;
     ///
     ///     for hit in response.hits {
     ///         println!("Project: {}", hit.title);
@@ -64,6 +48,42 @@ impl ModrinthAPI {
     ///     Ok(())
     /// }
     /// ```
+    pub async fn search_with(&self, query: &SearchQuery) -> Result<Response> {
+        self.client
+            .get(query.url_with_base(&self.base_url)?)
+            .custom_send_json()
+            .await
+    }
+
+    /// Performs an extended search for projects on Modrinth, allowing for more granular control over the search
+    /// results through various filtering options.
+    ///
+    /// This is a positional shorthand for [ModrinthAPI::search_with], which should be used instead.
+    ///
+    /// Since 0.3.0 the limit is validated before the request is sent: a `limit` of 0 or
+    /// above 100 returns [`Error::InvalidSearchLimit`], while 0.2.0 sent it to the API as is.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The search query string.
+    /// * `sort` - The sorting criteria for the search results (e.g., by downloads, recency).
+    /// * `limit` - An optional maximum number of results to return, between 1 and 100. Defaults to 20 if `None`.
+    /// * `extended_search` - A struct containing additional search parameters:
+    ///     * `offset` - An optional starting point for the results. Defaults to 0 if `None`.
+    ///     * `facets` - A vector of vectors of `Facet` enums, allowing for complex filtering.
+    ///       Each inner vector represents a group of facets, where results must match
+    ///       at least one facet from each inner group.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` which is:
+    /// * `Ok(Response)` - On successful retrieval of search results. The `Response` struct
+    ///   contains a list of `Hit` objects representing the found projects.
+    /// * `Err(Error)` - If the limit is out of range, or an error occurs during the API call or data processing.
+    #[deprecated(
+        since = "0.3.0",
+        note = "use search_with(&SearchQuery); since 0.3.0 a limit of 0 or above 100 returns Error::InvalidSearchLimit instead of being sent to the API"
+    )]
     pub async fn search(
         &self,
         query: &str,
@@ -71,25 +91,13 @@ impl ModrinthAPI {
         limit: Option<u32>,
         extended_search: Option<ExtendedSearch>,
     ) -> Result<Response> {
-        let limit = limit.unwrap_or(20);
-
-        let extended_search = extended_search.unwrap_or_default();
-        let offset = &extended_search.offset.unwrap_or(0);
-        let mut facets = extended_search.facets;
-
-        let mut url = BASE_URL
-            .join_all(vec!["search"])
-            .with_query("query", query)
-            .with_query("index", sort)
-            .with_query("limit", limit)
-            .with_query("offset", offset);
-
-        facets.retain(|e| !e.is_empty());
-        if !facets.is_empty() {
-            url = url.with_query_json("facets", facets)?
+        let mut search_query = SearchQuery::from(extended_search.unwrap_or_default())
+            .query(query)
+            .sort(*sort);
+        if let Some(limit) = limit {
+            search_query = search_query.limit(limit);
         }
-
-        self.client.get(url).custom_send_json().await
+        self.search_with(&search_query).await
     }
 
     /// Performs an extended search for projects on Modrinth, allowing for more granular control over the search
@@ -98,7 +106,7 @@ impl ModrinthAPI {
     /// This function is a reference to [ModrinthAPI::search] function (backward compatibility)
     #[deprecated(
         since = "0.2.0",
-        note = "This function is a backward compatibility with older versions of modrinth-api-rs. Please use ModrinthAPI::search instead"
+        note = "This function is a backward compatibility with older versions of modrinth-api-rs. Please use ModrinthAPI::search_with instead"
     )]
     pub async fn extended_search(
         &self,
@@ -107,6 +115,93 @@ impl ModrinthAPI {
         limit: Option<u32>,
         extended_search: Option<ExtendedSearch>,
     ) -> Result<Response> {
+        #[allow(deprecated)]
         self.search(query, sort, limit, extended_search).await
     }
+
+    /// Searches for projects on Modrinth and yields every matching hit, fetching the pages lazily.
+    ///
+    /// The offset is advanced after each page until [`Response::total_hits`] is exhausted.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The search query string.
+    /// * `sort` - The sorting criteria for the search results (e.g., by downloads, recency).
+    /// * `facets` - The facets to filter by, see [SearchQuery::facet_and] and [SearchQuery::facet_or].
+    /// * `page_size` - An optional number of results to request per page, between 1 and 100. Defaults to 100 if `None`.
+    /// * `max_results` - An optional maximum number of hits to yield. If `None`, all hits are yielded.
+    ///
+    /// # Returns
+    ///
+    /// A stream of `Result<SearchHit>`; it ends after the first `Err`, e.g.
+    /// [`Error::InvalidSearchLimit`] if `page_size` is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use futures_util::TryStreamExt;
+    /// use modrinth_api::{ModrinthAPI, structs::search::Sort};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let api = ModrinthAPI::default();
+    ///
+    ///     let hits: Vec<_> = api
+    ///         .search_stream("fabric", &Sort::Downloads, vec![], None, Some(250))
+    ///         .try_collect()
+    ///         .await?;
+    ///
+    ///     println!("{} projects", hits.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn search_stream<'a>(
+        &'a self,
+        query: &'a str,
+        sort: &'a Sort,
+        facets: Vec<Vec<Facet>>,
+        page_size: Option<u32>,
+        max_results: Option<usize>,
+    ) -> impl Stream<Item = Result<SearchHit>> + 'a {
+        let page_size = page_size.unwrap_or(100);
+        let max_results = max_results.unwrap_or(usize::MAX);
+
+        let pages = stream::try_unfold(Some(0), move |offset| {
+            let facets = facets.clone();
+            async move {
+                let Some(offset) = offset else {
+                    return Ok(None);
+                };
+                if !(1..=100).contains(&page_size) {
+                    return Err(Error::InvalidSearchLimit(page_size));
+                }
+
+                // Don't request more hits than are still wanted
+                let remaining = max_results - offset as usize;
+                let limit = page_size.min(remaining.try_into().unwrap_or(u32::MAX));
+                let response = self
+                    .search_with(
+                        &SearchQuery::from(ExtendedSearch {
+                            offset: Some(offset),
+                            facets,
+                        })
+                        .query(query)
+                        .sort(*sort)
+                        .limit(limit),
+                    )
+                    .await?;
+
+                let next = offset + response.hits.len() as u32;
+                let next = (!response.hits.is_empty()
+                    && (next as usize) < response.total_hits.min(max_results))
+                .then_some(next);
+                Ok(Some((response.hits, next)))
+            }
+        });
+
+        pages
+            .map_ok(|hits| stream::iter(hits.into_iter().map(Ok)))
+            .try_flatten()
+            .take(max_results)
+    }
 }
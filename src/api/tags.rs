@@ -0,0 +1,37 @@
+//! API functions to get tags (lists of values accepted by the API)
+
+use super::*;
+use crate::{
+    structs::{game_versions::GameVersionTag, reports::ReportType},
+    utils::{RequestBuilderCustomSend, UrlJoinAll},
+};
+
+impl ModrinthAPI {
+    /// Get the list of valid report types
+    ///
+    /// # Returns
+    ///
+    /// `Result<Vec<ReportType>>`:
+    /// - `Ok(Vec<ReportType>)`: A list of [`ReportType`] values accepted by [ModrinthAPI::create_report].
+    /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
+    pub async fn get_report_types(&self) -> Result<Vec<ReportType>> {
+        self.client
+            .get(self.base_url.join_all(vec!["tag", "report_type"]))
+            .custom_send_json()
+            .await
+    }
+
+    /// Get the list of Minecraft versions, from newest to oldest
+    ///
+    /// # Returns
+    ///
+    /// `Result<Vec<GameVersionTag>>`:
+    /// - `Ok(Vec<GameVersionTag>)`: The game versions with their release dates, see [sort_by_release_date](crate::structs::game_versions::sort_by_release_date).
+    /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
+    pub async fn get_game_versions(&self) -> Result<Vec<GameVersionTag>> {
+        self.client
+            .get(self.base_url.join_all(vec!["tag", "game_version"]))
+            .custom_send_json()
+            .await
+    }
+}
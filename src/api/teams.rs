@@ -0,0 +1,139 @@
+//! API functions related to teams
+//!
+//! Except for [ModrinthAPI::get_team_members], all of these require a client
+//! created with [ModrinthAPI::with_token].
+
+use super::*;
+use crate::{
+    structs::{
+        ids::{TeamId, UserId, UserRef},
+        teams::{EditTeamMember, TeamMember},
+    },
+    utils::{RequestBuilderCustomSend, UrlJoinAll, check_id_slug},
+};
+
+impl ModrinthAPI {
+    /// Get the members of a team
+    ///
+    /// # Arguments
+    ///
+    /// * `team_id` - The ID of the team ([`TeamId`]), e.g. [`Project::team`](crate::structs::projects::Project::team)
+    ///
+    /// # Returns
+    ///
+    /// `Result<Vec<TeamMember>>`:
+    /// - `Ok(Vec<TeamMember>)`: A list of the [`TeamMember`] structs.
+    /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
+    pub async fn get_team_members(&self, team_id: &TeamId) -> Result<Vec<TeamMember>> {
+        self.client
+            .get(
+                self.base_url
+                    .join_all(vec!["team", team_id.as_str(), "members"]),
+            )
+            .custom_send_json()
+            .await
+    }
+
+    /// Invite a user to a team
+    ///
+    /// # Arguments
+    ///
+    /// * `team_id` - The ID of the team ([`TeamId`])
+    /// * `user_id` - The ID of the user to invite ([`UserId`])
+    pub async fn add_team_member(&self, team_id: &TeamId, user_id: &UserId) -> Result<()> {
+        self.client
+            .post(
+                self.base_url
+                    .join_all(vec!["team", team_id.as_str(), "members"]),
+            )
+            .json(&serde_json::json!({ "user_id": user_id }))
+            .custom_send()
+            .await?;
+        Ok(())
+    }
+
+    /// Accept an invite to join a team
+    ///
+    /// # Arguments
+    ///
+    /// * `team_id` - The ID of the team ([`TeamId`])
+    pub async fn join_team(&self, team_id: &TeamId) -> Result<()> {
+        self.client
+            .post(
+                self.base_url
+                    .join_all(vec!["team", team_id.as_str(), "join"]),
+            )
+            .custom_send()
+            .await?;
+        Ok(())
+    }
+
+    /// Modify a team member's role, permissions, payouts split or ordering
+    ///
+    /// # Arguments
+    ///
+    /// * `team_id` - The ID of the team ([`TeamId`])
+    /// * `user` - The ID or username of the member ([`UserRef`], e.g. `&str` or [`UserId`])
+    /// * `edit` - The fields to change ([`EditTeamMember`])
+    pub async fn modify_team_member(
+        &self,
+        team_id: &TeamId,
+        user: impl Into<UserRef>,
+        edit: &EditTeamMember,
+    ) -> Result<()> {
+        let user = user.into();
+        check_id_slug(&[user.as_str()])?;
+        self.client
+            .patch(
+                self.base_url
+                    .join_all(vec!["team", team_id.as_str(), "members", user.as_str()]),
+            )
+            .json(edit)
+            .custom_send()
+            .await?;
+        Ok(())
+    }
+
+    /// Remove a member from a team
+    ///
+    /// # Arguments
+    ///
+    /// * `team_id` - The ID of the team ([`TeamId`])
+    /// * `user` - The ID or username of the member ([`UserRef`], e.g. `&str` or [`UserId`])
+    pub async fn remove_team_member(
+        &self,
+        team_id: &TeamId,
+        user: impl Into<UserRef>,
+    ) -> Result<()> {
+        let user = user.into();
+        check_id_slug(&[user.as_str()])?;
+        self.client
+            .delete(self.base_url.join_all(vec![
+                "team",
+                team_id.as_str(),
+                "members",
+                user.as_str(),
+            ]))
+            .custom_send()
+            .await?;
+        Ok(())
+    }
+
+    /// Transfer ownership of a team to another member
+    ///
+    /// # Arguments
+    ///
+    /// * `team_id` - The ID of the team ([`TeamId`])
+    /// * `user_id` - The ID of the new owner ([`UserId`]), who must already be a member of the team
+    pub async fn transfer_team_ownership(&self, team_id: &TeamId, user_id: &UserId) -> Result<()> {
+        self.client
+            .patch(
+                self.base_url
+                    .join_all(vec!["team", team_id.as_str(), "owner"]),
+            )
+            .json(&serde_json::json!({ "user_id": user_id }))
+            .custom_send()
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+//! API functions related to threads and moderation messages
+//!
+//! All of these require a client created with [ModrinthAPI::with_token].
+
+use super::*;
+use crate::{
+    structs::{
+        ids::{ThreadId, ThreadMessageId},
+        threads::{MessageBody, Thread},
+    },
+    utils::{RequestBuilderCustomSend, UrlJoinAll, UrlWithQuery},
+};
+
+impl ModrinthAPI {
+    /// Get a thread by its ID
+    ///
+    /// # Arguments
+    ///
+    /// * `thread_id` - The ID of the thread ([`ThreadId`]), e.g. [`Project::thread_id`](crate::structs::projects::Project::thread_id)
+    ///
+    /// # Returns
+    ///
+    /// `Result<Thread>`:
+    /// - `Ok(Thread)`: [`Thread`] struct.
+    /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
+    pub async fn get_thread(&self, thread_id: &ThreadId) -> Result<Thread> {
+        self.client
+            .get(self.base_url.join_all(vec!["thread", thread_id.as_str()]))
+            .custom_send_json()
+            .await
+    }
+
+    /// Get multiple threads by their IDs
+    ///
+    /// # Arguments
+    ///
+    /// * `thread_ids` - The IDs of the threads (`&[ThreadId]`)
+    ///
+    /// # Returns
+    ///
+    /// `Result<Vec<Thread>>`:
+    /// - `Ok(Vec<Thread>)`: A list of the [`Thread`] structs.
+    /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
+    pub async fn get_threads(&self, thread_ids: &[ThreadId]) -> Result<Vec<Thread>> {
+        self.client
+            .get(
+                self.base_url
+                    .join_all(vec!["threads"])
+                    .with_query_json("ids", thread_ids)?,
+            )
+            .custom_send_json()
+            .await
+    }
+
+    /// Send a message to a thread
+    ///
+    /// # Arguments
+    ///
+    /// * `thread_id` - The ID of the thread ([`ThreadId`])
+    /// * `body` - The message to send, usually [`MessageBody::text`]
+    ///
+    /// # Returns
+    ///
+    /// `Result<()>`:
+    /// - `Ok(())`: The message was sent, fetch the thread with [ModrinthAPI::get_thread] to see it.
+    /// - `Err(crate::error::Error)`: An error occurred during the API request (e.g. missing token).
+    pub async fn send_thread_message(
+        &self,
+        thread_id: &ThreadId,
+        body: &MessageBody,
+    ) -> Result<()> {
+        self.client
+            .post(self.base_url.join_all(vec!["thread", thread_id.as_str()]))
+            .json(&serde_json::json!({ "body": body }))
+            .custom_send()
+            .await?;
+        Ok(())
+    }
+
+    /// Delete a thread message
+    ///
+    /// # Arguments
+    ///
+    /// * `message_id` - The ID of the message ([`ThreadMessageId`])
+    pub async fn delete_thread_message(&self, message_id: &ThreadMessageId) -> Result<()> {
+        self.client
+            .delete(self.base_url.join_all(vec!["message", message_id.as_str()]))
+            .custom_send()
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,153 @@
+//! API functions related to users
+
+use super::*;
+use crate::{
+    structs::{
+        ids::UserRef,
+        projects::Project,
+        users::{EditUser, PayoutHistory, User},
+    },
+    utils::{
+        RequestBuilderCustomSend, UrlJoinAll, UrlWithQuery, check_id_slug, image_content_type,
+    },
+};
+use reqwest::header::CONTENT_TYPE;
+
+impl ModrinthAPI {
+    /// Get a user by their ID or username
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The ID or username of the user ([`UserRef`], e.g. `&str` or [`UserId`](crate::structs::ids::UserId))
+    ///
+    /// # Returns
+    ///
+    /// `Result<User>`:
+    /// - `Ok(User)`: [`User`] struct.
+    /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
+    pub async fn get_user(&self, user: impl Into<UserRef>) -> Result<User> {
+        let user = user.into();
+        check_id_slug(&[user.as_str()])?;
+        self.client
+            .get(self.base_url.join_all(vec!["user", user.as_str()]))
+            .custom_send_json()
+            .await
+    }
+
+    /// Modify a user's profile
+    ///
+    /// Requires a client created with [ModrinthAPI::with_token].
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The ID or username of the user ([`UserRef`], e.g. `&str` or [`UserId`](crate::structs::ids::UserId))
+    /// * `edit` - The fields to change ([`EditUser`])
+    pub async fn modify_user(&self, user: impl Into<UserRef>, edit: &EditUser) -> Result<()> {
+        let user = user.into();
+        check_id_slug(&[user.as_str()])?;
+        self.client
+            .patch(self.base_url.join_all(vec!["user", user.as_str()]))
+            .json(edit)
+            .custom_send()
+            .await?;
+        Ok(())
+    }
+
+    /// Change a user's avatar
+    ///
+    /// Requires a client created with [ModrinthAPI::with_token].
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The ID or username of the user ([`UserRef`], e.g. `&str` or [`UserId`](crate::structs::ids::UserId))
+    /// * `file_ext` - The image file extension, e.g. `png`, `jpg`, `webp` or `gif` (`&str`)
+    /// * `icon` - The raw image data
+    pub async fn change_user_icon(
+        &self,
+        user: impl Into<UserRef>,
+        file_ext: &str,
+        icon: Vec<u8>,
+    ) -> Result<()> {
+        let user = user.into();
+        check_id_slug(&[user.as_str()])?;
+        self.client
+            .patch(
+                self.base_url
+                    .join_all(vec!["user", user.as_str(), "icon"])
+                    .with_query("ext", file_ext),
+            )
+            .header(CONTENT_TYPE, image_content_type(file_ext))
+            .body(icon)
+            .custom_send()
+            .await?;
+        Ok(())
+    }
+
+    /// Remove a user's avatar
+    ///
+    /// Requires a client created with [ModrinthAPI::with_token].
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The ID or username of the user ([`UserRef`], e.g. `&str` or [`UserId`](crate::structs::ids::UserId))
+    pub async fn delete_user_icon(&self, user: impl Into<UserRef>) -> Result<()> {
+        let user = user.into();
+        check_id_slug(&[user.as_str()])?;
+        self.client
+            .delete(self.base_url.join_all(vec!["user", user.as_str(), "icon"]))
+            .custom_send()
+            .await?;
+        Ok(())
+    }
+
+    /// Get a user's payout history
+    ///
+    /// Requires a client created with [ModrinthAPI::with_token].
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The ID or username of the user ([`UserRef`], e.g. `&str` or [`UserId`](crate::structs::ids::UserId))
+    ///
+    /// # Returns
+    ///
+    /// `Result<PayoutHistory>`:
+    /// - `Ok(PayoutHistory)`: [`PayoutHistory`] struct.
+    /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
+    pub async fn get_payout_history(&self, user: impl Into<UserRef>) -> Result<PayoutHistory> {
+        let user = user.into();
+        check_id_slug(&[user.as_str()])?;
+        self.client
+            .get(
+                self.base_url
+                    .join_all(vec!["user", user.as_str(), "payouts"]),
+            )
+            .custom_send_json()
+            .await
+    }
+
+    /// Get the projects followed by a user
+    ///
+    /// Requires a client created with [ModrinthAPI::with_token]; only the
+    /// authenticated user may list their own followed projects.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The ID or username of the user ([`UserRef`], e.g. `&str` or [`UserId`](crate::structs::ids::UserId))
+    ///
+    /// # Returns
+    ///
+    /// `Result<Vec<Project>>`:
+    /// - `Ok(Vec<Project>)`: A list of the followed [`Project`] structs.
+    /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
+    pub async fn get_followed_projects(&self, user: impl Into<UserRef>) -> Result<Vec<Project>> {
+        let user = user.into();
+        check_id_slug(&[user.as_str()])?;
+        self.client
+            .get(
+                self.base_url
+                    .join_all(vec!["user", user.as_str(), "follows"]),
+            )
+            .custom_send_json()
+            .await
+    }
+}
@@ -0,0 +1,138 @@
+//! v3 API functions related to analytics
+//!
+//! All of these require a client created with [ModrinthAPI::with_token],
+//! and only return data for projects the user is allowed to view analytics of.
+
+use super::*;
+use crate::{
+    structs::v3::analytics::{Analytics, AnalyticsFilter, CountryAnalytics, Revenue},
+    utils::{RequestBuilderCustomSend, UrlJoinAll, UrlWithQuery, check_id_slug},
+};
+use serde::de::DeserializeOwned;
+
+impl ModrinthAPIv3<'_> {
+    /// Get the number of downloads of projects over time
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - The projects, time range and resolution ([`AnalyticsFilter`])
+    ///
+    /// # Returns
+    ///
+    /// `Result<Analytics<u64>>`:
+    /// - `Ok(Analytics<u64>)`: The downloads per time slice, keyed by project ID.
+    /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
+    pub async fn get_downloads_analytics(
+        &self,
+        filter: &AnalyticsFilter<'_>,
+    ) -> Result<Analytics<u64>> {
+        self.get_analytics(vec!["analytics", "downloads"], filter)
+            .await
+    }
+
+    /// Get the number of page views of projects over time
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - The projects, time range and resolution ([`AnalyticsFilter`])
+    ///
+    /// # Returns
+    ///
+    /// `Result<Analytics<u64>>`:
+    /// - `Ok(Analytics<u64>)`: The views per time slice, keyed by project ID.
+    /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
+    pub async fn get_views_analytics(
+        &self,
+        filter: &AnalyticsFilter<'_>,
+    ) -> Result<Analytics<u64>> {
+        self.get_analytics(vec!["analytics", "views"], filter).await
+    }
+
+    /// Get the revenue of projects over time
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - The projects, time range and resolution ([`AnalyticsFilter`])
+    ///
+    /// # Returns
+    ///
+    /// `Result<Analytics<Revenue>>`:
+    /// - `Ok(Analytics<Revenue>)`: The revenue per time slice, keyed by project ID.
+    /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
+    pub async fn get_revenue_analytics(
+        &self,
+        filter: &AnalyticsFilter<'_>,
+    ) -> Result<Analytics<Revenue>> {
+        self.get_analytics(vec!["analytics", "revenue"], filter)
+            .await
+    }
+
+    /// Get the number of downloads of projects by country
+    ///
+    /// The `resolution_minutes` of the filter is ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - The projects and time range ([`AnalyticsFilter`])
+    ///
+    /// # Returns
+    ///
+    /// `Result<CountryAnalytics>`:
+    /// - `Ok(CountryAnalytics)`: The downloads per country, keyed by project ID.
+    /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
+    pub async fn get_downloads_by_country(
+        &self,
+        filter: &AnalyticsFilter<'_>,
+    ) -> Result<CountryAnalytics> {
+        self.get_analytics(vec!["analytics", "countries", "downloads"], filter)
+            .await
+    }
+
+    /// Get the number of page views of projects by country
+    ///
+    /// The `resolution_minutes` of the filter is ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - The projects and time range ([`AnalyticsFilter`])
+    ///
+    /// # Returns
+    ///
+    /// `Result<CountryAnalytics>`:
+    /// - `Ok(CountryAnalytics)`: The views per country, keyed by project ID.
+    /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
+    pub async fn get_views_by_country(
+        &self,
+        filter: &AnalyticsFilter<'_>,
+    ) -> Result<CountryAnalytics> {
+        self.get_analytics(vec!["analytics", "countries", "views"], filter)
+            .await
+    }
+
+    async fn get_analytics<T: DeserializeOwned>(
+        &self,
+        segments: Vec<&str>,
+        filter: &AnalyticsFilter<'_>,
+    ) -> Result<T> {
+        if let Some(project_ids) = filter.project_ids {
+            check_id_slug(project_ids)?;
+        }
+
+        let mut url = self
+            .api
+            .base_url_v3
+            .join_all(segments)
+            .add_optional_query_json("project_ids", filter.project_ids)?;
+        if let Some(start_date) = filter.start_date {
+            url = url.with_query("start_date", start_date.to_rfc3339());
+        }
+        if let Some(end_date) = filter.end_date {
+            url = url.with_query("end_date", end_date.to_rfc3339());
+        }
+        if let Some(resolution_minutes) = filter.resolution_minutes {
+            url = url.with_query("resolution_minutes", resolution_minutes);
+        }
+
+        self.api.client.get(url).custom_send_json().await
+    }
+}
@@ -0,0 +1,155 @@
+//! v3 API functions related to collections
+//!
+//! Creating, modifying and deleting collections requires a client created with [ModrinthAPI::with_token].
+
+use super::*;
+use crate::{
+    structs::{
+        ids::{CollectionId, UserRef},
+        v3::collections::{Collection, CreateCollection, EditCollection},
+    },
+    utils::{RequestBuilderCustomSend, UrlJoinAll, UrlWithQuery, check_id_slug},
+};
+
+impl ModrinthAPIv3<'_> {
+    /// Get a collection by its ID
+    ///
+    /// # Arguments
+    ///
+    /// * `collection_id` - The ID of the collection ([`CollectionId`])
+    ///
+    /// # Returns
+    ///
+    /// `Result<Collection>`:
+    /// - `Ok(Collection)`: [`Collection`] struct.
+    /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
+    pub async fn get_collection(&self, collection_id: &CollectionId) -> Result<Collection> {
+        self.api
+            .client
+            .get(
+                self.api
+                    .base_url_v3
+                    .join_all(vec!["collection", collection_id.as_str()]),
+            )
+            .custom_send_json()
+            .await
+    }
+
+    /// Get multiple collections by their IDs
+    ///
+    /// # Arguments
+    ///
+    /// * `collection_ids` - The IDs of the collections (`&[CollectionId]`)
+    ///
+    /// # Returns
+    ///
+    /// `Result<Vec<Collection>>`:
+    /// - `Ok(Vec<Collection>)`: A list of the [`Collection`] structs.
+    /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
+    pub async fn get_collections(
+        &self,
+        collection_ids: &[CollectionId],
+    ) -> Result<Vec<Collection>> {
+        self.api
+            .client
+            .get(
+                self.api
+                    .base_url_v3
+                    .join_all(vec!["collections"])
+                    .with_query_json("ids", collection_ids)?,
+            )
+            .custom_send_json()
+            .await
+    }
+
+    /// Get the collections of a user
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The ID or username of the user ([`UserRef`], e.g. `&str` or [`UserId`](crate::structs::ids::UserId))
+    ///
+    /// # Returns
+    ///
+    /// `Result<Vec<Collection>>`:
+    /// - `Ok(Vec<Collection>)`: A list of the [`Collection`] structs visible to the client.
+    /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
+    pub async fn get_user_collections(&self, user: impl Into<UserRef>) -> Result<Vec<Collection>> {
+        let user = user.into();
+        check_id_slug(&[user.as_str()])?;
+        self.api
+            .client
+            .get(
+                self.api
+                    .base_url_v3
+                    .join_all(vec!["user", user.as_str(), "collections"]),
+            )
+            .custom_send_json()
+            .await
+    }
+
+    /// Create a collection owned by the authenticated user
+    ///
+    /// # Arguments
+    ///
+    /// * `collection` - The collection to create ([`CreateCollection`])
+    ///
+    /// # Returns
+    ///
+    /// `Result<Collection>`:
+    /// - `Ok(Collection)`: The created [`Collection`] struct.
+    /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
+    pub async fn create_collection(&self, collection: &CreateCollection) -> Result<Collection> {
+        check_id_slug(&collection.projects)?;
+        self.api
+            .client
+            .post(self.api.base_url_v3.join_all(vec!["collection"]))
+            .json(collection)
+            .custom_send_json()
+            .await
+    }
+
+    /// Modify a collection
+    ///
+    /// # Arguments
+    ///
+    /// * `collection_id` - The ID of the collection ([`CollectionId`])
+    /// * `edit` - The fields to change ([`EditCollection`])
+    pub async fn modify_collection(
+        &self,
+        collection_id: &CollectionId,
+        edit: &EditCollection,
+    ) -> Result<()> {
+        if let Some(projects) = &edit.projects {
+            check_id_slug(projects)?;
+        }
+        self.api
+            .client
+            .patch(
+                self.api
+                    .base_url_v3
+                    .join_all(vec!["collection", collection_id.as_str()]),
+            )
+            .json(edit)
+            .custom_send()
+            .await?;
+        Ok(())
+    }
+
+    /// Delete a collection
+    ///
+    /// # Arguments
+    ///
+    /// * `collection_id` - The ID of the collection ([`CollectionId`])
+    pub async fn delete_collection(&self, collection_id: &CollectionId) -> Result<()> {
+        self.api
+            .client
+            .delete(
+                self.api
+                    .base_url_v3
+                    .join_all(vec!["collection", collection_id.as_str()]),
+            )
+            .custom_send()
+            .await?;
+        Ok(())
+    }
+}
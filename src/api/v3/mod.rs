@@ -0,0 +1,25 @@
+//! API functions for the Labrinth v3 API
+//!
+//! The v3 API is reached through [ModrinthAPI::v3], which reuses the client
+//! (user agent, token) of the [ModrinthAPI] it was created from.
+
+pub mod analytics;
+pub mod collections;
+pub mod organizations;
+pub mod projects;
+pub mod versions;
+
+use crate::{ModrinthAPI, Result};
+
+/// A view of a [ModrinthAPI] client that talks to the v3 API
+#[derive(Debug, Clone, Copy)]
+pub struct ModrinthAPIv3<'a> {
+    api: &'a ModrinthAPI,
+}
+
+impl ModrinthAPI {
+    /// Returns a handle for calling v3 endpoints with this client
+    pub fn v3(&self) -> ModrinthAPIv3<'_> {
+        ModrinthAPIv3 { api: self }
+    }
+}
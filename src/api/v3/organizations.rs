@@ -0,0 +1,259 @@
+//! v3 API functions related to organizations
+//!
+//! Except for the getters, all of these require a client created with [ModrinthAPI::with_token].
+
+use super::*;
+use crate::{
+    structs::{
+        ids::{OrganizationRef, ProjectRef, UserId},
+        v3::{
+            organizations::{CreateOrganization, EditOrganization, Organization},
+            projects::Project,
+        },
+    },
+    utils::{
+        RequestBuilderCustomSend, UrlJoinAll, UrlWithQuery, check_id_slug, image_content_type,
+    },
+};
+use reqwest::header::CONTENT_TYPE;
+
+impl ModrinthAPIv3<'_> {
+    /// Get an organization by its ID or slug
+    ///
+    /// # Arguments
+    ///
+    /// * `organization` - The ID or slug of the organization ([`OrganizationRef`], e.g. `&str` or [`OrganizationId`](crate::structs::ids::OrganizationId))
+    ///
+    /// # Returns
+    ///
+    /// `Result<Organization>`:
+    /// - `Ok(Organization)`: [`Organization`] struct.
+    /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
+    pub async fn get_organization(
+        &self,
+        organization: impl Into<OrganizationRef>,
+    ) -> Result<Organization> {
+        let organization = organization.into();
+        check_id_slug(&[organization.as_str()])?;
+        self.api
+            .client
+            .get(
+                self.api
+                    .base_url_v3
+                    .join_all(vec!["organization", organization.as_str()]),
+            )
+            .custom_send_json()
+            .await
+    }
+
+    /// Get multiple organizations by their IDs or slugs
+    ///
+    /// # Arguments
+    ///
+    /// * `organizations` - The IDs or slugs of the organizations (`&[&str]`)
+    ///
+    /// # Returns
+    ///
+    /// `Result<Vec<Organization>>`:
+    /// - `Ok(Vec<Organization>)`: A list of the [`Organization`] structs.
+    /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
+    pub async fn get_organizations(&self, organizations: &[&str]) -> Result<Vec<Organization>> {
+        check_id_slug(organizations)?;
+        self.api
+            .client
+            .get(
+                self.api
+                    .base_url_v3
+                    .join_all(vec!["organizations"])
+                    .with_query_json("ids", organizations)?,
+            )
+            .custom_send_json()
+            .await
+    }
+
+    /// Get the projects owned by an organization
+    ///
+    /// # Arguments
+    ///
+    /// * `organization` - The ID or slug of the organization ([`OrganizationRef`], e.g. `&str` or [`OrganizationId`](crate::structs::ids::OrganizationId))
+    ///
+    /// # Returns
+    ///
+    /// `Result<Vec<Project>>`:
+    /// - `Ok(Vec<Project>)`: A list of the [`Project`] structs.
+    /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
+    pub async fn get_organization_projects(
+        &self,
+        organization: impl Into<OrganizationRef>,
+    ) -> Result<Vec<Project>> {
+        let organization = organization.into();
+        check_id_slug(&[organization.as_str()])?;
+        self.api
+            .client
+            .get(self.api.base_url_v3.join_all(vec![
+                "organization",
+                organization.as_str(),
+                "projects",
+            ]))
+            .custom_send_json()
+            .await
+    }
+
+    /// Create an organization owned by the authenticated user
+    ///
+    /// # Arguments
+    ///
+    /// * `organization` - The organization to create ([`CreateOrganization`])
+    ///
+    /// # Returns
+    ///
+    /// `Result<Organization>`:
+    /// - `Ok(Organization)`: The created [`Organization`] struct.
+    /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
+    pub async fn create_organization(
+        &self,
+        organization: &CreateOrganization,
+    ) -> Result<Organization> {
+        check_id_slug(&[&organization.slug])?;
+        self.api
+            .client
+            .post(self.api.base_url_v3.join_all(vec!["organization"]))
+            .json(organization)
+            .custom_send_json()
+            .await
+    }
+
+    /// Modify an organization
+    ///
+    /// # Arguments
+    ///
+    /// * `organization` - The ID or slug of the organization ([`OrganizationRef`], e.g. `&str` or [`OrganizationId`](crate::structs::ids::OrganizationId))
+    /// * `edit` - The fields to change ([`EditOrganization`])
+    pub async fn modify_organization(
+        &self,
+        organization: impl Into<OrganizationRef>,
+        edit: &EditOrganization,
+    ) -> Result<()> {
+        let organization = organization.into();
+        check_id_slug(&[organization.as_str()])?;
+        self.api
+            .client
+            .patch(
+                self.api
+                    .base_url_v3
+                    .join_all(vec!["organization", organization.as_str()]),
+            )
+            .json(edit)
+            .custom_send()
+            .await?;
+        Ok(())
+    }
+
+    /// Delete an organization
+    ///
+    /// # Arguments
+    ///
+    /// * `organization` - The ID or slug of the organization ([`OrganizationRef`], e.g. `&str` or [`OrganizationId`](crate::structs::ids::OrganizationId))
+    pub async fn delete_organization(
+        &self,
+        organization: impl Into<OrganizationRef>,
+    ) -> Result<()> {
+        let organization = organization.into();
+        check_id_slug(&[organization.as_str()])?;
+        self.api
+            .client
+            .delete(
+                self.api
+                    .base_url_v3
+                    .join_all(vec!["organization", organization.as_str()]),
+            )
+            .custom_send()
+            .await?;
+        Ok(())
+    }
+
+    /// Move a project into an organization
+    ///
+    /// # Arguments
+    ///
+    /// * `organization` - The ID or slug of the organization ([`OrganizationRef`], e.g. `&str` or [`OrganizationId`](crate::structs::ids::OrganizationId))
+    /// * `project` - The ID or slug of the project ([`ProjectRef`], e.g. `&str` or [`ProjectId`](crate::structs::ids::ProjectId))
+    pub async fn add_organization_project(
+        &self,
+        organization: impl Into<OrganizationRef>,
+        project: impl Into<ProjectRef>,
+    ) -> Result<()> {
+        let (organization, project) = (organization.into(), project.into());
+        check_id_slug(&[organization.as_str(), project.as_str()])?;
+        self.api
+            .client
+            .post(self.api.base_url_v3.join_all(vec![
+                "organization",
+                organization.as_str(),
+                "projects",
+            ]))
+            .json(&serde_json::json!({ "project_id": project.as_str() }))
+            .custom_send()
+            .await?;
+        Ok(())
+    }
+
+    /// Remove a project from an organization
+    ///
+    /// # Arguments
+    ///
+    /// * `organization` - The ID or slug of the organization ([`OrganizationRef`], e.g. `&str` or [`OrganizationId`](crate::structs::ids::OrganizationId))
+    /// * `project` - The ID or slug of the project ([`ProjectRef`], e.g. `&str` or [`ProjectId`](crate::structs::ids::ProjectId))
+    /// * `new_owner` - The ID of the organization member who becomes the owner of the project ([`UserId`])
+    pub async fn remove_organization_project(
+        &self,
+        organization: impl Into<OrganizationRef>,
+        project: impl Into<ProjectRef>,
+        new_owner: &UserId,
+    ) -> Result<()> {
+        let (organization, project) = (organization.into(), project.into());
+        check_id_slug(&[organization.as_str(), project.as_str()])?;
+        self.api
+            .client
+            .delete(self.api.base_url_v3.join_all(vec![
+                "organization",
+                organization.as_str(),
+                "projects",
+                project.as_str(),
+            ]))
+            .json(&serde_json::json!({ "new_owner": new_owner }))
+            .custom_send()
+            .await?;
+        Ok(())
+    }
+
+    /// Change an organization's icon
+    ///
+    /// # Arguments
+    ///
+    /// * `organization` - The ID or slug of the organization ([`OrganizationRef`], e.g. `&str` or [`OrganizationId`](crate::structs::ids::OrganizationId))
+    /// * `file_ext` - The image file extension, e.g. `png`, `jpg`, `webp` or `gif` (`&str`)
+    /// * `icon` - The raw image data
+    pub async fn change_organization_icon(
+        &self,
+        organization: impl Into<OrganizationRef>,
+        file_ext: &str,
+        icon: Vec<u8>,
+    ) -> Result<()> {
+        let organization = organization.into();
+        check_id_slug(&[organization.as_str()])?;
+        self.api
+            .client
+            .patch(
+                self.api
+                    .base_url_v3
+                    .join_all(vec!["organization", organization.as_str(), "icon"])
+                    .with_query("ext", file_ext),
+            )
+            .header(CONTENT_TYPE, image_content_type(file_ext))
+            .body(icon)
+            .custom_send()
+            .await?;
+        Ok(())
+    }
+}
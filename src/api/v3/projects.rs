@@ -0,0 +1,59 @@
+//! v3 API functions to get projects by id/slug
+
+use super::*;
+use crate::{
+    structs::{ids::ProjectRef, v3::projects::Project},
+    utils::{RequestBuilderCustomSend, UrlJoinAll, UrlWithQuery, check_id_slug},
+};
+
+impl ModrinthAPIv3<'_> {
+    /// Get information about a v3 [Project] by project slug (id)
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - A project slug/id ([`ProjectRef`], e.g. `&str` or [`ProjectId`](crate::structs::ids::ProjectId))
+    ///
+    /// # Returns
+    ///
+    /// `Result<Project>`:
+    /// - `Ok(Project)`: The [`Project`] data.
+    /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
+    pub async fn get_project(&self, project_id: impl Into<ProjectRef>) -> Result<Project> {
+        let project_id = project_id.into();
+        check_id_slug(&[project_id.as_str()])?;
+        self.api
+            .client
+            .get(
+                self.api
+                    .base_url_v3
+                    .join_all(vec!["project", project_id.as_str()]),
+            )
+            .custom_send_json()
+            .await
+    }
+
+    /// Get multiple v3 projects by their slugs (ids)
+    ///
+    /// # Arguments
+    ///
+    /// * `project_ids` - The project slugs/ids (`&[&str]`)
+    ///
+    /// # Returns
+    ///
+    /// `Result<Vec<Project>>`:
+    /// - `Ok(Vec<Project>)`: A list of the [`Project`] structs.
+    /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
+    pub async fn get_projects(&self, project_ids: &[&str]) -> Result<Vec<Project>> {
+        check_id_slug(project_ids)?;
+        self.api
+            .client
+            .get(
+                self.api
+                    .base_url_v3
+                    .join_all(vec!["projects"])
+                    .with_query_json("ids", project_ids)?,
+            )
+            .custom_send_json()
+            .await
+    }
+}
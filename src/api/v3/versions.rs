@@ -0,0 +1,63 @@
+//! v3 API functions related to files (versions)
+
+use super::*;
+use crate::{
+    structs::{
+        ids::{ProjectRef, VersionId},
+        v3::versions::Version,
+    },
+    utils::{RequestBuilderCustomSend, UrlJoinAll, check_id_slug},
+};
+
+impl ModrinthAPIv3<'_> {
+    /// Get all v3 versions of a project
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - Project slug/id ([`ProjectRef`], e.g. `&str` or [`ProjectId`](crate::structs::ids::ProjectId))
+    ///
+    /// # Returns
+    ///
+    /// `Result<Vec<Version>>`:
+    /// - `Ok(Vec<Version>)`: A list of the [`Version`] structs.
+    /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
+    pub async fn get_project_versions(
+        &self,
+        project_id: impl Into<ProjectRef>,
+    ) -> Result<Vec<Version>> {
+        let project_id = project_id.into();
+        check_id_slug(&[project_id.as_str()])?;
+        self.api
+            .client
+            .get(
+                self.api
+                    .base_url_v3
+                    .join_all(vec!["project", project_id.as_str(), "version"]),
+            )
+            .custom_send_json()
+            .await
+    }
+
+    /// Get a v3 version from the version id
+    ///
+    /// # Arguments
+    ///
+    /// * `version_id` - The ID of the version ([`VersionId`])
+    ///
+    /// # Returns
+    ///
+    /// `Result<Version>`:
+    /// - `Ok(Version)`: [`Version`] struct.
+    /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
+    pub async fn get_version(&self, version_id: &VersionId) -> Result<Version> {
+        self.api
+            .client
+            .get(
+                self.api
+                    .base_url_v3
+                    .join_all(vec!["version", version_id.as_str()]),
+            )
+            .custom_send_json()
+            .await
+    }
+}
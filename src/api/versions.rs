@@ -2,7 +2,11 @@
 
 use super::*;
 use crate::{
-    structs::versions::*,
+    Error,
+    structs::{
+        ids::{ProjectRef, VersionId},
+        versions::*,
+    },
     utils::{RequestBuilderCustomSend, UrlJoinAll, UrlWithQuery, check_id_slug},
 };
 
@@ -10,18 +14,17 @@ impl ModrinthAPI {
     /// Retrieves a list of project versions with custom filtering.
     ///
     /// # Arguments
-    /// * `project_id` - Project slug/id (`&str`).
-    /// * `extra_options` - `Option<ProjectVersionsFilter<'_>>` ([`ProjectVersionsFilter`]): Optional parameters to filter the list of versions
-    ///   or to retrieve a specific version.
+    /// * `project_id` - Project slug/id ([`ProjectRef`], e.g. `&str` or [`ProjectId`](crate::structs::ids::ProjectId)).
+    /// * `extra_options` - `Option<ProjectVersionsFilter<'_>>` ([`ProjectVersionsFilter`]): Optional parameters to filter the list of versions.
     ///
     ///   If `extra_options` is `None`, all versions for the project will be returned without any filters.
     ///
     ///   Example usage of `extra_options`:
     ///   ```no_run
-    ///   use modrinth_api::structs::versions::ProjectVersionsFilter;
+    ///   use modrinth_api::structs::{loaders::Loader, versions::ProjectVersionsFilter};
     ///
     ///   let options_for_filtered_list = ProjectVersionsFilter {
-    ///       loaders: Some(&["fabric"]),
+    ///       loaders: Some(&[Loader::Fabric]),
     ///       game_versions: Some(&["1.20.1"]),
     ///       featured: Some(true),
     ///   };
@@ -29,23 +32,21 @@ impl ModrinthAPI {
     ///
     /// # Returns
     /// `Result<Vec<Version>>`:
-    /// - `Ok(Vec<Version>)`: A list of the [`Version`] structs. If `number` was specified in `extra_options`,
-    ///   this vector will contain at most one [`Version`] struct.
+    /// - `Ok(Vec<Version>)`: A list of the [`Version`] structs matching all the filters.
     /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
     pub async fn get_project_versions(
         &self,
-        project_id: &str,
+        project_id: impl Into<ProjectRef>,
         extra_options: Option<ProjectVersionsFilter<'_>>,
     ) -> Result<Vec<Version>> {
-        check_id_slug(&[project_id])?;
+        let project_id = project_id.into();
+        check_id_slug(&[project_id.as_str()])?;
 
-        let mut url = BASE_URL.join_all(vec!["project", project_id, "version"]);
+        let mut url = self
+            .base_url
+            .join_all(vec!["project", project_id.as_str(), "version"]);
 
         if let Some(extra_options) = extra_options {
-            url = BASE_URL.join_all(vec![
-                "project", project_id, "version",
-                // extra_options.number.unwrap_or(""),
-            ]);
             url = url.add_optional_query_json("loaders", extra_options.loaders)?;
             url = url.add_optional_query_json("game_versions", extra_options.game_versions)?;
             url = url.add_optional_query_json("featured", extra_options.featured)?;
@@ -57,68 +58,53 @@ impl ModrinthAPI {
     /// Get the version from the version id
     ///
     /// # Arguments
-    /// * `version_id` - The ID of the version (`&str`)
+    /// * `version_id` - The ID of the version ([`VersionId`])
     ///
     /// # Returns
     ///
     /// `Result<Version>`:
     /// - `Ok(Version)`: [`Version`] struct.
     /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
-    pub async fn get_version_by_id(&self, version_id: &str) -> Result<Version> {
-        check_id_slug(&[version_id])?;
+    pub async fn get_version_by_id(&self, version_id: &VersionId) -> Result<Version> {
         self.client
-            .get(BASE_URL.join_all(vec!["version", version_id]))
+            .get(self.base_url.join_all(vec!["version", version_id.as_str()]))
             .custom_send_json()
             .await
     }
 
-    /// Retrieves a project [`Version`] with custom filtering.
+    /// Get a version of a project from its version number or ID
     ///
-    /// # Arguments
-    /// * `project_id` - Project slug/id (`&str`).
-    /// * `extra_options` - `Option<ProjectVersionParams<'_>>`: Optional parameters to filter the list of versions
-    ///   or to retrieve a specific version.
-    ///
-    ///   If `extra_options` is `None`, all versions for the project will be returned without any filters.
+    /// If the version number matches multiple versions, only the oldest matching version is returned.
+    /// To filter by loader or game version, use [ModrinthAPI::get_project_versions].
     ///
-    ///   Example usage of `extra_options`:
-    ///   ```no_run
-    ///   use modrinth_api::structs::versions::ProjectVersionParams;
-    ///   
-    ///   let options_for_filtered_list = ProjectVersionParams {
-    ///       number: Some("mc1.20.1-0.5.13-fabric"), // or Some("OihdIimA")
-    ///       loaders: Some(&["fabric"]),
-    ///       game_versions: Some(&["1.20.1"]),
-    ///       featured: Some(true),
-    ///   };
-    ///   ```
+    /// # Arguments
+    /// * `project_id` - Project slug/id ([`ProjectRef`], e.g. `&str` or [`ProjectId`](crate::structs::ids::ProjectId)).
+    /// * `number_or_id` - The version number (e.g. `mc1.20.1-0.5.13-fabric`) or the version ID (e.g. `OihdIimA`).
     ///
     /// # Returns
-    /// `Result<Vec<Version>>`:
-    /// - `Ok(Vec<Version>)`: A list of the [`Version`] structs. If `number` was specified in `extra_options`,
-    ///   this vector will contain at most one [`Version`] struct.
+    /// `Result<Option<Version>>`:
+    /// - `Ok(Some(Version))`: The matching [`Version`].
+    /// - `Ok(None)`: The project has no such version, or does not exist.
     /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
     pub async fn get_project_version(
         &self,
-        project_id: &str,
-        extra_options: Option<ProjectVersionParams<'_>>,
-    ) -> Result<Version> {
-        check_id_slug(&[project_id])?;
-
-        let mut url = BASE_URL.join_all(vec!["project", project_id, "version"]);
-
-        if let Some(extra_options) = extra_options {
-            url = BASE_URL.join_all(vec![
-                "project",
-                project_id,
-                "version",
-                extra_options.number.unwrap(),
-            ]);
-            url = url.add_optional_query_json("loaders", extra_options.loaders)?;
-            url = url.add_optional_query_json("game_versions", extra_options.game_versions)?;
-            url = url.add_optional_query_json("featured", extra_options.featured)?;
+        project_id: impl Into<ProjectRef>,
+        number_or_id: &str,
+    ) -> Result<Option<Version>> {
+        let project_id = project_id.into();
+        check_id_slug(&[project_id.as_str()])?;
+        if number_or_id.is_empty() {
+            return Err(Error::InvalidIDorSlug);
         }
 
-        self.client.get(url).custom_send_json().await
+        let mut url = self
+            .base_url
+            .join_all(vec!["project", project_id.as_str(), "version"]);
+        // Pushed as a path segment so that it is escaped, version numbers can contain any character
+        url.path_segments_mut()
+            .map_err(|()| url::ParseError::RelativeUrlWithCannotBeABaseBase)?
+            .push(number_or_id);
+
+        self.client.get(url).custom_send_json_optional().await
     }
 }
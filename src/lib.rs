@@ -1,6 +1,13 @@
-use std::sync::LazyLock;
+use std::{
+    collections::HashMap,
+    sync::{Arc, LazyLock, RwLock},
+};
 
-use reqwest::{Client, header::InvalidHeaderValue};
+use reqwest::{
+    Client,
+    header::{AUTHORIZATION, HeaderMap, HeaderValue, InvalidHeaderValue},
+};
+use structs::ids::ProjectId;
 use url::Url;
 
 pub mod api;
@@ -14,6 +21,14 @@ pub static BASE_URL: LazyLock<Url> = LazyLock::new(|| {
         .expect("Invalid API base URL")
 });
 
+#[cfg(feature = "v3")]
+pub static BASE_URL_V3: LazyLock<Url> = LazyLock::new(|| {
+    Url::parse("https://api.modrinth.com/")
+        .expect("Invalid base URL")
+        .join(concat!("v", "3", "/"))
+        .expect("Invalid API base URL")
+});
+
 #[derive(thiserror::Error, Debug)]
 #[error(transparent)]
 pub enum Error {
@@ -21,11 +36,22 @@ pub enum Error {
     InvalidIDorSlug,
     #[error("Invalid SHA1 hash")]
     InvalidSHA1,
+    #[error("Invalid SHA512 hash")]
+    InvalidSHA512,
+    #[error("Invalid game version range: {0}")]
+    InvalidGameVersionRange(String),
+    #[error("Invalid facet: {0}")]
+    InvalidFacet(String),
+    #[error("Search limit must be between 1 and 100, got {0}")]
+    InvalidSearchLimit(u32),
+    #[error("Color must be a 24-bit RGB value, got {0:#x}")]
+    InvalidColor(u32),
     #[error("You have been rate limited, please wait for {0} seconds")]
     RateLimitExceeded(usize),
     #[error("The API at {} is deprecated", *BASE_URL)]
     ApiDeprecated,
     ReqwestError(#[from] reqwest::Error),
+    IOError(#[from] std::io::Error),
     JSONError(#[from] serde_json::Error),
     InvalidHeaderValue(#[from] InvalidHeaderValue),
     ParseError(#[from] url::ParseError),
@@ -35,12 +61,19 @@ pub type Result<T> = std::result::Result<T, Error>;
 #[derive(Debug, Clone)]
 pub struct ModrinthAPI {
     client: Client,
+    /// Root of the v2 API, [BASE_URL] unless changed with [ModrinthAPI::with_base_url]
+    base_url: Url,
+    /// Root of the v3 API, [BASE_URL_V3] unless changed with [ModrinthAPI::with_base_url]
+    #[cfg(feature = "v3")]
+    base_url_v3: Url,
+    /// Project IDs by slug (or ID-shaped string), shared between clones (see [ModrinthAPI::resolve_project_id])
+    project_ids: Arc<RwLock<HashMap<String, ProjectId>>>,
 }
 
 impl Default for ModrinthAPI {
     fn default() -> Self {
-        Self {
-            client: Client::builder()
+        Self::from_client(
+            Client::builder()
                 .user_agent(concat!(
                     env!("CARGO_CRATE_NAME"),
                     "/",
@@ -48,11 +81,41 @@ impl Default for ModrinthAPI {
                 ))
                 .build()
                 .expect("Failed to initialize TLS backend"),
-        }
+        )
     }
 }
 
 impl ModrinthAPI {
+    fn from_client(client: Client) -> Self {
+        Self {
+            client,
+            base_url: BASE_URL.clone(),
+            #[cfg(feature = "v3")]
+            base_url_v3: BASE_URL_V3.clone(),
+            project_ids: Default::default(),
+        }
+    }
+
+    /// Sends the API requests to `base_url` instead of `https://api.modrinth.com/` (e.g. a mirror or a mock server)
+    ///
+    /// `base_url` is the root of the API, the versioned paths (`v2/`, `v3/`) are appended to it,
+    /// so it should end with a `/`, e.g. `http://localhost:8080/`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [Error::ParseError] if `base_url` cannot be a base (e.g. `mailto:` or `data:` URLs).
+    pub fn with_base_url(mut self, base_url: Url) -> Result<Self> {
+        if base_url.cannot_be_a_base() {
+            return Err(url::ParseError::RelativeUrlWithCannotBeABaseBase.into());
+        }
+        self.base_url = base_url.join("v2/")?;
+        #[cfg(feature = "v3")]
+        {
+            self.base_url_v3 = base_url.join("v3/")?;
+        }
+        Ok(self)
+    }
+
     fn client_builder(
         name: &str,
         version: Option<&str>,
@@ -67,10 +130,38 @@ impl ModrinthAPI {
     }
 
     pub fn new(name: &str, version: Option<&str>, contact: Option<&str>) -> Self {
-        Self {
-            client: Self::client_builder(name, version, contact)
+        Self::from_client(
+            Self::client_builder(name, version, contact)
                 .build()
                 .expect("Failed to initialise TLS backend"),
-        }
+        )
+    }
+
+    /// Creates a client that sends the given personal access token (PAT) with every request.
+    ///
+    /// A token is required for endpoints that act on behalf of a user
+    /// (e.g. [ModrinthAPI::follow_project]).
+    ///
+    /// # Errors
+    ///
+    /// Returns [Error::InvalidHeaderValue] if `token` contains characters that are not allowed in a header.
+    pub fn with_token(
+        name: &str,
+        version: Option<&str>,
+        contact: Option<&str>,
+        token: &str,
+    ) -> Result<Self> {
+        let mut token = HeaderValue::from_str(token)?;
+        token.set_sensitive(true);
+
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, token);
+
+        Ok(Self::from_client(
+            Self::client_builder(name, version, contact)
+                .default_headers(headers)
+                .build()
+                .expect("Failed to initialise TLS backend"),
+        ))
     }
 }
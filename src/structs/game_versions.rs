@@ -0,0 +1,309 @@
+//! Minecraft game versions
+//!
+//! [GameVersion] parses the version strings used by Modrinth and orders them
+//! correctly (so `1.9 < 1.20`), [GameVersionRange] matches them against
+//! requirements like `>=1.20, <1.21`.
+
+use super::*;
+use crate::{Error, structs::search::Op};
+use lazy_regex::regex_captures;
+use std::{cmp::Ordering, fmt::Display, str::FromStr};
+
+/// A Minecraft version, e.g. `1.20.1`, `1.20-pre1`, `1.20.1-rc2` or `23w45a`
+///
+/// Versions that are neither releases nor weekly snapshots (old alphas and betas,
+/// April Fools versions...) are kept as [GameVersionKind::Other].
+///
+/// Releases (including pre-releases and release candidates) and snapshots are ordered among
+/// themselves. Without release dates a snapshot cannot be placed between releases, so all
+/// snapshots sort before all releases (even `23w45a < 1.0`), and other versions before
+/// snapshots; use [sort_by_release_date] with the dates from
+/// [ModrinthAPI::get_game_versions](crate::ModrinthAPI::get_game_versions) for a chronological order.
+///
+/// Versions written differently but naming the same release (`1.21` and `1.21.0`) are not equal,
+/// but [GameVersion::cmp_version] and [GameVersionRange] treat them as the same version.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub struct GameVersion {
+    version: String,
+    kind: GameVersionKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GameVersionKind {
+    /// A release, pre-release or release candidate
+    Release {
+        major: u32,
+        minor: u32,
+        patch: u32,
+        stage: ReleaseStage,
+    },
+    /// A weekly snapshot, e.g. `23w45a`
+    Snapshot { year: u32, week: u32, build: char },
+    /// Any other version
+    Other,
+}
+
+/// The stage of a release, in the order they are published
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ReleaseStage {
+    /// e.g. `1.20-pre1`
+    PreRelease(u32),
+    /// e.g. `1.20-rc1`
+    ReleaseCandidate(u32),
+    /// e.g. `1.20`
+    Release,
+}
+
+impl GameVersion {
+    /// Returns the version as sent by the API
+    pub fn as_str(&self) -> &str {
+        &self.version
+    }
+
+    /// Returns the parsed form of the version
+    pub fn kind(&self) -> GameVersionKind {
+        self.kind
+    }
+
+    /// Whether the version is a full release (not a pre-release, release candidate or snapshot)
+    pub fn is_release(&self) -> bool {
+        matches!(
+            self.kind,
+            GameVersionKind::Release {
+                stage: ReleaseStage::Release,
+                ..
+            }
+        )
+    }
+
+    /// Whether the version is a weekly snapshot
+    pub fn is_snapshot(&self) -> bool {
+        matches!(self.kind, GameVersionKind::Snapshot { .. })
+    }
+}
+
+fn parse_kind(version: &str) -> Option<GameVersionKind> {
+    if let Some((_, year, week, build)) = regex_captures!(r"^(\d{2})w(\d{2})([a-z])$", version) {
+        return Some(GameVersionKind::Snapshot {
+            year: year.parse().ok()?,
+            week: week.parse().ok()?,
+            build: build.chars().next()?,
+        });
+    }
+
+    let (_, major, minor, patch, pre, rc) = regex_captures!(
+        r"^(\d+)\.(\d+)(?:\.(\d+))?(?:-pre(\d+)| Pre-Release (\d+))?(?:-rc(\d+)| Release Candidate (\d+))?$",
+        version
+    )
+    .map(|(all, major, minor, patch, pre, pre_old, rc, rc_old)| {
+        let pre = if pre.is_empty() { pre_old } else { pre };
+        let rc = if rc.is_empty() { rc_old } else { rc };
+        (all, major, minor, patch, pre, rc)
+    })?;
+    let stage = match (pre, rc) {
+        ("", "") => ReleaseStage::Release,
+        (pre, "") => ReleaseStage::PreRelease(pre.parse().ok()?),
+        ("", rc) => ReleaseStage::ReleaseCandidate(rc.parse().ok()?),
+        _ => return None,
+    };
+    Some(GameVersionKind::Release {
+        major: major.parse().ok()?,
+        minor: minor.parse().ok()?,
+        patch: if patch.is_empty() {
+            0
+        } else {
+            patch.parse().ok()?
+        },
+        stage,
+    })
+}
+
+impl From<String> for GameVersion {
+    fn from(version: String) -> Self {
+        let kind = parse_kind(&version).unwrap_or(GameVersionKind::Other);
+        Self { version, kind }
+    }
+}
+
+impl From<&str> for GameVersion {
+    fn from(version: &str) -> Self {
+        version.to_string().into()
+    }
+}
+
+impl FromStr for GameVersion {
+    type Err = std::convert::Infallible;
+
+    fn from_str(version: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(version.into())
+    }
+}
+
+impl From<GameVersion> for String {
+    fn from(version: GameVersion) -> Self {
+        version.version
+    }
+}
+
+impl Display for GameVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.version)
+    }
+}
+
+impl PartialEq<str> for GameVersion {
+    fn eq(&self, other: &str) -> bool {
+        self.version == other
+    }
+}
+
+impl PartialEq<&str> for GameVersion {
+    fn eq(&self, other: &&str) -> bool {
+        self.version == *other
+    }
+}
+
+impl GameVersion {
+    /// Compares the versions they name, ignoring how they are written (`1.21` is equal to `1.21.0`)
+    ///
+    /// This is the order of [GameVersion] without its final tie-break on the raw string.
+    pub fn cmp_version(&self, other: &Self) -> Ordering {
+        use GameVersionKind::*;
+
+        let rank = |kind: &GameVersionKind| match kind {
+            Other => 0,
+            Snapshot { .. } => 1,
+            Release { .. } => 2,
+        };
+        match (self.kind, other.kind) {
+            (
+                Release {
+                    major,
+                    minor,
+                    patch,
+                    stage,
+                },
+                Release {
+                    major: other_major,
+                    minor: other_minor,
+                    patch: other_patch,
+                    stage: other_stage,
+                },
+            ) => (major, minor, patch, stage).cmp(&(
+                other_major,
+                other_minor,
+                other_patch,
+                other_stage,
+            )),
+            (
+                Snapshot { year, week, build },
+                Snapshot {
+                    year: other_year,
+                    week: other_week,
+                    build: other_build,
+                },
+            ) => (year, week, build).cmp(&(other_year, other_week, other_build)),
+            (Other, Other) => self.version.cmp(&other.version),
+            (kind, other_kind) => rank(&kind).cmp(&rank(&other_kind)),
+        }
+    }
+}
+
+impl Ord for GameVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Fall back to the raw string so that the order is consistent with `Eq` (e.g. `1.21` and `1.21.0`)
+        self.cmp_version(other)
+            .then_with(|| self.version.cmp(&other.version))
+    }
+}
+
+impl PartialOrd for GameVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// A set of requirements on a [GameVersion], e.g. `>=1.20, <1.21`
+///
+/// Each comma separated requirement is an operator (`=`, `!=`, `>`, `>=`, `<`, `<=`,
+/// defaulting to `=`) followed by a version; a version matches if it meets all of them.
+/// Comparisons use [GameVersion::cmp_version], so `=1.21` matches `1.21.0`, and snapshots
+/// never match a range bounded by releases since they sort below every release.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GameVersionRange {
+    requirements: Vec<(Op, GameVersion)>,
+}
+
+impl GameVersionRange {
+    /// Whether `version` meets all requirements of the range
+    pub fn matches(&self, version: &GameVersion) -> bool {
+        self.requirements.iter().all(|(op, bound)| {
+            let ordering = version.cmp_version(bound);
+            match op {
+                Op::Eq => ordering.is_eq(),
+                Op::Ne => ordering.is_ne(),
+                Op::Gt => ordering.is_gt(),
+                Op::Ge => ordering.is_ge(),
+                Op::Lt => ordering.is_lt(),
+                Op::Le => ordering.is_le(),
+            }
+        })
+    }
+}
+
+impl FromStr for GameVersionRange {
+    type Err = Error;
+
+    fn from_str(range: &str) -> Result<Self> {
+        let requirements = range
+            .split(',')
+            .map(|requirement| {
+                let requirement = requirement.trim();
+                let operator_end = requirement
+                    .find(|c: char| !matches!(c, '=' | '!' | '<' | '>'))
+                    .unwrap_or(requirement.len());
+                let (operator, version) = requirement.split_at(operator_end);
+                let op = match operator {
+                    "" => Op::Eq,
+                    operator => operator
+                        .parse()
+                        .map_err(|_| Error::InvalidGameVersionRange(range.to_string()))?,
+                };
+                let version = version.trim();
+                if version.is_empty() {
+                    return Err(Error::InvalidGameVersionRange(range.to_string()));
+                }
+                Ok((op, GameVersion::from(version)))
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self { requirements })
+    }
+}
+
+/// A game version as listed by [ModrinthAPI::get_game_versions](crate::ModrinthAPI::get_game_versions)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct GameVersionTag {
+    pub version: GameVersion,
+    /// The type of the version (`release`, `snapshot`, `alpha` or `beta`)
+    pub version_type: String,
+    /// The date of the release of the version
+    pub date: Date,
+    /// Whether this is a major version, used for Featured Versions
+    pub major: bool,
+}
+
+/// Sorts `versions` chronologically, using the release dates in `tags`
+///
+/// This also orders snapshots between releases, which the order of [GameVersion] alone cannot.
+/// Versions without a date in `tags` are placed after all dated versions, in the order of [GameVersion].
+pub fn sort_by_release_date(versions: &mut [GameVersion], tags: &[GameVersionTag]) {
+    let dates: std::collections::HashMap<&str, Date> = tags
+        .iter()
+        .map(|tag| (tag.version.as_str(), tag.date))
+        .collect();
+    versions.sort_by(|a, b| {
+        let (a_date, b_date) = (dates.get(a.as_str()), dates.get(b.as_str()));
+        (a_date.is_none(), a_date, a).cmp(&(b_date.is_none(), b_date, b))
+    });
+}
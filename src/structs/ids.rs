@@ -0,0 +1,197 @@
+//! Typed Modrinth IDs
+//!
+//! Modrinth IDs are 8 characters of base62 encoding a `u64`. Each kind of ID
+//! has its own type, so that e.g. a [VersionId] cannot be passed where a
+//! [ProjectId] is expected.
+
+use crate::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::{fmt::Display, str::FromStr};
+
+const BASE62_ALPHABET: &[u8; 62] =
+    b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Decodes a base62 Modrinth ID, returning `None` if it is not valid
+fn decode_base62(id: &str) -> Option<u64> {
+    if id.len() != 8 {
+        return None;
+    }
+    id.bytes().try_fold(0u64, |value, byte| {
+        let digit = BASE62_ALPHABET.iter().position(|&c| c == byte)?;
+        value.checked_mul(62)?.checked_add(digit as u64)
+    })
+}
+
+macro_rules! id_type {
+    ($($(#[$meta:meta])* $name:ident),* $(,)?) => {$(
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+        #[serde(try_from = "String", into = "String")]
+        pub struct $name(String);
+
+        impl $name {
+            /// Returns the base62 representation of the ID
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            /// Returns the number encoded by the ID
+            pub fn to_u64(&self) -> u64 {
+                decode_base62(&self.0).expect("ID is validated on construction")
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Error;
+
+            fn from_str(id: &str) -> Result<Self> {
+                id.to_string().try_into()
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = Error;
+
+            fn try_from(id: String) -> Result<Self> {
+                match decode_base62(&id) {
+                    Some(_) => Ok(Self(id)),
+                    None => Err(Error::InvalidIDorSlug),
+                }
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+    )*};
+}
+
+id_type!(
+    /// The ID of a project, e.g. `AANobbMI`
+    ProjectId,
+    /// The ID of a version, e.g. `OihdIimA`
+    VersionId,
+    /// The ID of a user, e.g. `DzLrfrbK`
+    UserId,
+    /// The ID of a team, e.g. `4reLOAKe`
+    TeamId,
+    /// The ID of an organization
+    OrganizationId,
+    /// The ID of a collection
+    CollectionId,
+    /// The ID of a notification
+    NotificationId,
+    /// The ID of a report
+    ReportId,
+    /// The ID of a thread
+    ThreadId,
+    /// The ID of a message in a thread
+    ThreadMessageId,
+);
+
+macro_rules! ref_type {
+    ($(#[$meta:meta])* $name:ident($id:ident, $name_variant:ident)) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            Id($id),
+            $name_variant(String),
+        }
+
+        impl $name {
+            /// Returns the ID or name as it is used in URLs
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $name::Id(id) => id.as_str(),
+                    $name::$name_variant(id_or_name) => id_or_name,
+                }
+            }
+        }
+
+        impl From<$id> for $name {
+            fn from(id: $id) -> Self {
+                $name::Id(id)
+            }
+        }
+
+        impl From<&$id> for $name {
+            fn from(id: &$id) -> Self {
+                $name::Id(id.clone())
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(id_or_name: String) -> Self {
+                $name::$name_variant(id_or_name)
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(id_or_name: &str) -> Self {
+                id_or_name.to_string().into()
+            }
+        }
+
+        impl From<&String> for $name {
+            fn from(id_or_name: &String) -> Self {
+                id_or_name.clone().into()
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+    };
+}
+
+ref_type!(
+    /// A reference to a project, either by its [ProjectId] or by its slug
+    ///
+    /// Most endpoints accept both, so API methods take `impl Into<ProjectRef>`.
+    /// Only a typed [ProjectId] becomes [ProjectRef::Id]. A `&str` or `String` is always
+    /// [ProjectRef::IdOrSlug], even if it looks like an ID: slugs can be 8 alphanumeric
+    /// characters too (e.g. `debugify`), so only the API can tell which one it is.
+    ProjectRef(ProjectId, IdOrSlug)
+);
+
+ref_type!(
+    /// A reference to a user, either by its [UserId] or by its username
+    ///
+    /// Like [ProjectRef], only a typed [UserId] becomes [UserRef::Id].
+    UserRef(UserId, IdOrUsername)
+);
+
+ref_type!(
+    /// A reference to an organization, either by its [OrganizationId] or by its slug
+    ///
+    /// Like [ProjectRef], only a typed [OrganizationId] becomes [OrganizationRef::Id].
+    OrganizationRef(OrganizationId, IdOrSlug)
+);
@@ -0,0 +1,153 @@
+//! Mod, plugin and shader loaders
+//!
+//! [documentation](https://docs.modrinth.com/api/operations/loaderlist/)
+
+use super::*;
+use std::{fmt::Display, str::FromStr};
+
+/// A loader a version runs on, as sent by the API (e.g. `fabric`, `neoforge`)
+///
+/// Loaders not known to this crate deserialize to [Loader::Other].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum Loader {
+    // Mod loaders
+    Fabric,
+    Forge,
+    NeoForge,
+    Quilt,
+    #[serde(rename = "legacy-fabric")]
+    LegacyFabric,
+    Babric,
+    Ornithe,
+    Rift,
+    LiteLoader,
+    ModLoader,
+    NilLoader,
+    #[serde(rename = "java-agent")]
+    JavaAgent,
+    // Plugin loaders
+    Bukkit,
+    Spigot,
+    Paper,
+    Purpur,
+    Folia,
+    Sponge,
+    BungeeCord,
+    Waterfall,
+    Velocity,
+    Geyser,
+    // Shader loaders
+    Iris,
+    OptiFine,
+    Canvas,
+    Vanilla,
+    /// Resource packs
+    Minecraft,
+    /// Data packs
+    Datapack,
+    /// A value not known to this crate
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl Loader {
+    /// Whether the loader loads mods (e.g. Fabric, Forge)
+    pub fn is_mod_loader(&self) -> bool {
+        matches!(
+            self,
+            Loader::Fabric
+                | Loader::Forge
+                | Loader::NeoForge
+                | Loader::Quilt
+                | Loader::LegacyFabric
+                | Loader::Babric
+                | Loader::Ornithe
+                | Loader::Rift
+                | Loader::LiteLoader
+                | Loader::ModLoader
+                | Loader::NilLoader
+                | Loader::JavaAgent
+        )
+    }
+
+    /// Whether the loader loads server plugins, including proxies (e.g. Paper, Velocity)
+    pub fn is_plugin_loader(&self) -> bool {
+        self.is_proxy()
+            || matches!(
+                self,
+                Loader::Bukkit
+                    | Loader::Spigot
+                    | Loader::Paper
+                    | Loader::Purpur
+                    | Loader::Folia
+                    | Loader::Sponge
+                    | Loader::Geyser
+            )
+    }
+
+    /// Whether the loader is a proxy server (e.g. Velocity, BungeeCord)
+    pub fn is_proxy(&self) -> bool {
+        matches!(
+            self,
+            Loader::BungeeCord | Loader::Waterfall | Loader::Velocity
+        )
+    }
+
+    /// Whether the loader loads shaders (e.g. Iris, OptiFine)
+    pub fn is_shader_loader(&self) -> bool {
+        matches!(
+            self,
+            Loader::Iris | Loader::OptiFine | Loader::Canvas | Loader::Vanilla
+        )
+    }
+}
+
+/// Formats the loader as it is sent to the API (e.g. `neoforge`)
+impl Display for Loader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Loader::Fabric => "fabric",
+            Loader::Forge => "forge",
+            Loader::NeoForge => "neoforge",
+            Loader::Quilt => "quilt",
+            Loader::LegacyFabric => "legacy-fabric",
+            Loader::Babric => "babric",
+            Loader::Ornithe => "ornithe",
+            Loader::Rift => "rift",
+            Loader::LiteLoader => "liteloader",
+            Loader::ModLoader => "modloader",
+            Loader::NilLoader => "nilloader",
+            Loader::JavaAgent => "java-agent",
+            Loader::Bukkit => "bukkit",
+            Loader::Spigot => "spigot",
+            Loader::Paper => "paper",
+            Loader::Purpur => "purpur",
+            Loader::Folia => "folia",
+            Loader::Sponge => "sponge",
+            Loader::BungeeCord => "bungeecord",
+            Loader::Waterfall => "waterfall",
+            Loader::Velocity => "velocity",
+            Loader::Geyser => "geyser",
+            Loader::Iris => "iris",
+            Loader::OptiFine => "optifine",
+            Loader::Canvas => "canvas",
+            Loader::Vanilla => "vanilla",
+            Loader::Minecraft => "minecraft",
+            Loader::Datapack => "datapack",
+            Loader::Other(loader) => loader,
+        })
+    }
+}
+
+/// Parses a loader case-insensitively, unknown loaders become [Loader::Other]
+impl FromStr for Loader {
+    type Err = std::convert::Infallible;
+
+    fn from_str(loader: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(
+            serde_json::from_value(serde_json::Value::String(loader.to_lowercase()))
+                .unwrap_or_else(|_| Loader::Other(loader.to_string())),
+        )
+    }
+}
@@ -1,7 +1,18 @@
+pub mod game_versions;
+pub mod ids;
+pub mod loaders;
+pub mod notifications;
 pub mod projects;
+pub mod reports;
 pub mod search;
+pub mod teams;
+pub mod threads;
+pub mod users;
 pub mod versions;
 
+#[cfg(feature = "v3")]
+pub mod v3;
+
 use crate::{ModrinthAPI, Result, structs::projects::Project};
 use serde::{Deserialize, Serialize};
 use url::Url;
@@ -0,0 +1,96 @@
+//! Models related to notifications
+//!
+//! [documentation](https://docs.modrinth.com/api/operations/tags/notifications/)
+
+use super::*;
+use crate::structs::{
+    ids::{
+        NotificationId, OrganizationId, ProjectId, ReportId, TeamId, ThreadId, ThreadMessageId,
+        UserId, VersionId,
+    },
+    projects::ProjectStatus,
+};
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct Notification {
+    /// The ID of the notification
+    pub id: NotificationId,
+    /// The ID of the user who received the notification
+    pub user_id: UserId,
+    /// The type of notification (e.g. `project_update`, `team_invite`)
+    #[serde(rename = "type")]
+    pub notification_type: Option<String>,
+    /// The title of the notification
+    pub title: String,
+    /// The body text of the notification
+    pub text: String,
+    /// A link to related project or version
+    pub link: String,
+    /// Whether the notification has been read or not
+    pub read: bool,
+    /// The time at which the notification was created
+    pub created: Date,
+    /// A list of actions that can be performed
+    pub actions: Vec<NotificationAction>,
+    /// The structured content of the notification
+    #[serde(default)]
+    pub body: Option<NotificationBody>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NotificationAction {
+    /// The friendly name for this action
+    pub title: String,
+    /// The HTTP method and the route of the API endpoint to call for this action
+    pub action_route: (String, String),
+}
+
+/// The structured content of a [Notification], tagged by its `type`
+///
+/// Types not known to this crate are deserialised as [NotificationBody::Unknown].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum NotificationBody {
+    /// A followed project has published a new version
+    ProjectUpdate {
+        project_id: ProjectId,
+        version_id: VersionId,
+    },
+    /// The user has been invited to join a project team
+    TeamInvite {
+        project_id: ProjectId,
+        team_id: TeamId,
+        invited_by: UserId,
+        role: String,
+    },
+    /// The user has been invited to join an organization
+    OrganizationInvite {
+        organization_id: OrganizationId,
+        invited_by: UserId,
+        team_id: TeamId,
+        role: String,
+    },
+    /// The status of one of the user's projects has changed
+    StatusChange {
+        project_id: ProjectId,
+        old_status: ProjectStatus,
+        new_status: ProjectStatus,
+    },
+    /// A moderator has sent a message in a thread
+    ModeratorMessage {
+        thread_id: ThreadId,
+        message_id: ThreadMessageId,
+        project_id: Option<ProjectId>,
+        report_id: Option<ReportId>,
+    },
+    /// A notification created before structured bodies existed
+    LegacyMarkdown {
+        notification_type: Option<String>,
+        title: String,
+        text: String,
+        link: String,
+        actions: Vec<NotificationAction>,
+    },
+    #[serde(other)]
+    Unknown,
+}
@@ -3,9 +3,18 @@
 //! [documentation](https://docs.modrinth.com/api/operations/tags/projects/)
 
 use super::*;
+use crate::{
+    Error,
+    structs::{
+        game_versions::GameVersion,
+        ids::{OrganizationId, ProjectId, TeamId, ThreadId, VersionId},
+        loaders::Loader,
+    },
+};
 use serde::{Deserialize, Serialize};
+use std::fmt::Display;
 
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
 pub struct Project {
     /// The slug of a project, used for vanity URLs
     pub slug: String,
@@ -21,6 +30,9 @@ pub struct Project {
     pub server_side: ProjectSupportRange,
     /// A long form description of the project
     pub body: String,
+    /// The link to the long description of the project (always null, only for legacy compatibility)
+    #[serde(default)]
+    pub body_url: Option<Url>,
     /// The status of the project
     pub status: ProjectStatus,
     /// The requested status when submitting for review or scheduling the project for release
@@ -28,27 +40,34 @@ pub struct Project {
     /// A list of categories which are searchable but non-primary
     pub additional_categories: Vec<String>,
     /// An optional link to where to submit bugs or issues with the project
-    pub issues_url: Option<String>,
+    pub issues_url: Option<Url>,
     /// An optional link to the source code of the project
-    pub source_url: Option<String>,
+    pub source_url: Option<Url>,
     /// An optional link to the project’s wiki page or other relevant information
-    pub wiki_url: Option<String>,
+    pub wiki_url: Option<Url>,
     /// An optional invite link to the project’s discord
-    pub discord_url: Option<String>,
+    pub discord_url: Option<Url>,
     /// Donation links / urls
     pub donation_urls: Vec<DonationLink>,
-    pub project_type: String,
+    /// The project type of the project
+    pub project_type: ProjectType,
     pub downloads: usize,
     /// Project icon URL
-    pub icon_url: Option<String>,
+    pub icon_url: Option<Url>,
     /// The RGB color of the project, automatically generated from the project icon
-    pub color: Option<usize>,
+    pub color: Option<Color>,
     /// The ID of the moderation thread associated with this project
-    pub thread_id: String,
+    pub thread_id: ThreadId,
     pub monetization_status: MonetizationStatus,
-    pub id: String,
+    /// A message that a moderator sent regarding the project
+    #[serde(default)]
+    pub moderator_message: Option<ModeratorMessage>,
+    pub id: ProjectId,
     /// The ID of the team that has ownership of this project
-    pub team: String,
+    pub team: TeamId,
+    /// The ID of the organization that owns this project, if any
+    #[serde(default)]
+    pub organization: Option<OrganizationId>,
     pub published: Date,
     pub updated: Date,
     /// The date the project's status was approved
@@ -57,11 +76,13 @@ pub struct Project {
     pub followers: usize,
     pub license: License,
     /// A list of the version IDs of the project (will never be empty unless draft status)
-    pub versions: Vec<String>,
+    pub versions: Vec<VersionId>,
     /// A list of all the game versions supported by the project
-    pub game_versions: Vec<String>,
+    pub game_versions: Vec<GameVersion>,
     /// A list of all the loaders supported by the project
-    pub loaders: Vec<String>,
+    pub loaders: Vec<Loader>,
+    /// A list of images that have been uploaded to the project's gallery
+    pub gallery: Vec<GalleryItem>,
 }
 
 impl Project {
@@ -74,7 +95,7 @@ impl Project {
     }
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 #[serde(rename_all = "lowercase")]
 pub enum ProjectType {
     /// Can be a mod, plugin, or data pack
@@ -85,24 +106,99 @@ pub enum ProjectType {
     Modpack,
     Datapack,
     ResourcePack,
+    /// A value not known to this crate
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Formats the project type as it is sent to the API (e.g. `resourcepack`)
+impl Display for ProjectType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ProjectType::Project => "project",
+            ProjectType::Mod => "mod",
+            ProjectType::Shader => "shader",
+            ProjectType::Plugin => "plugin",
+            ProjectType::Modpack => "modpack",
+            ProjectType::Datapack => "datapack",
+            ProjectType::ResourcePack => "resourcepack",
+            ProjectType::Other(project_type) => project_type,
+        })
+    }
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 #[serde(rename_all = "kebab-case")]
 pub enum MonetizationStatus {
     Monetized,
     Demonetized,
     ForceDemonetized,
+    /// A value not known to this crate
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// An RGB color, sent by the API as a packed integer (`0xRRGGBB`)
+///
+/// Formats as a hex color code, e.g. `#1bd96a`. Integers above `0xFFFFFF` are rejected,
+/// both by [Color::try_from] and when deserialising.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(try_from = "u32", into = "u32")]
+pub struct Color(u32);
+
+impl Color {
+    /// Creates a color from its red, green and blue components
+    pub fn from_rgb(r: u8, g: u8, b: u8) -> Self {
+        Color(u32::from_be_bytes([0, r, g, b]))
+    }
+
+    /// Returns the red component
+    pub fn r(&self) -> u8 {
+        (self.0 >> 16) as u8
+    }
+
+    /// Returns the green component
+    pub fn g(&self) -> u8 {
+        (self.0 >> 8) as u8
+    }
+
+    /// Returns the blue component
+    pub fn b(&self) -> u8 {
+        self.0 as u8
+    }
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+impl TryFrom<u32> for Color {
+    type Error = Error;
+
+    fn try_from(color: u32) -> Result<Self> {
+        if color > 0xFFFFFF {
+            return Err(Error::InvalidColor(color));
+        }
+        Ok(Color(color))
+    }
+}
+
+impl From<Color> for u32 {
+    fn from(color: Color) -> Self {
+        color.0
+    }
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.r(), self.g(), self.b())
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 pub struct License {
     pub id: String,
     pub name: String,
-    pub url: Option<String>,
+    pub url: Option<Url>,
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[serde(rename_all = "lowercase")]
 pub enum ProjectSupportRange {
     Required,
@@ -111,7 +207,19 @@ pub enum ProjectSupportRange {
     Unknown,
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+/// Formats the support range as it is sent to the API (e.g. `required`)
+impl Display for ProjectSupportRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ProjectSupportRange::Required => "required",
+            ProjectSupportRange::Optional => "optional",
+            ProjectSupportRange::Unsupported => "unsupported",
+            ProjectSupportRange::Unknown => "unknown",
+        })
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 #[serde(rename_all = "lowercase")]
 pub enum ProjectStatus {
     Approved,
@@ -124,9 +232,12 @@ pub enum ProjectStatus {
     Scheduled,
     Private,
     Unknown,
+    /// A value not known to this crate
+    #[serde(untagged)]
+    Other(String),
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 #[serde(rename_all = "lowercase")]
 pub enum RequestedStatus {
     Approved,
@@ -136,9 +247,36 @@ pub enum RequestedStatus {
     Draft,
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 pub struct DonationLink {
     pub id: String,
     pub platform: String,
-    pub url: String,
+    pub url: Url,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct ModeratorMessage {
+    /// The message that a moderator has left for the project
+    pub message: String,
+    /// The longer body of the message that a moderator has left for the project
+    pub body: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct GalleryItem {
+    /// The URL of the gallery image, usually a compressed preview
+    pub url: Url,
+    /// The URL of the image as it was uploaded
+    #[serde(default)]
+    pub raw_url: Option<Url>,
+    /// Whether the image is featured in the gallery
+    pub featured: bool,
+    /// The title of the gallery image
+    pub title: Option<String>,
+    /// The description of the gallery image
+    pub description: Option<String>,
+    /// The date and time the gallery image was created
+    pub created: Date,
+    /// The order of the gallery image, images are sorted by this (from least to greatest)
+    pub ordering: i64,
 }
@@ -0,0 +1,72 @@
+//! Models related to reports
+//!
+//! [documentation](https://docs.modrinth.com/api/operations/tags/misc/)
+
+use super::*;
+use crate::structs::ids::{ReportId, ThreadId, UserId};
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    /// The ID of the report
+    pub id: ReportId,
+    /// The type of the report being sent
+    pub report_type: ReportType,
+    /// The ID of the item (project, version, or user) being reported
+    pub item_id: String,
+    /// The type of the item being reported
+    pub item_type: ReportItemType,
+    /// The extended explanation of the report
+    pub body: String,
+    /// The ID of the user who reported the item
+    pub reporter: UserId,
+    /// The time at which the report was created
+    pub created: Date,
+    /// Whether the report is resolved
+    pub closed: bool,
+    /// The ID of the moderation thread associated with this report
+    pub thread_id: ThreadId,
+}
+
+/// The kind of a report, as listed by [ModrinthAPI::get_report_types]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "kebab-case")]
+pub enum ReportType {
+    Spam,
+    Copyright,
+    Inappropriate,
+    Malicious,
+    NameSquatting,
+    /// Any other type, including the API's own `other`
+    #[serde(untagged)]
+    Other(String),
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportItemType {
+    Project,
+    Version,
+    User,
+}
+
+/// Body of [ModrinthAPI::create_report]
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct CreateReport {
+    pub report_type: ReportType,
+    /// The ID of the item (project, version, or user) being reported
+    pub item_id: String,
+    pub item_type: ReportItemType,
+    /// The extended explanation of the report
+    pub body: String,
+}
+
+/// Body of [ModrinthAPI::edit_report], only the fields set to `Some` are changed
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct EditReport {
+    /// The new extended explanation of the report
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// Whether the report should be marked as resolved (moderators only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub closed: Option<bool>,
+}
@@ -1,13 +1,146 @@
 use super::*;
-use std::fmt::Display;
+use crate::{
+    BASE_URL, Error,
+    structs::{
+        game_versions::GameVersion,
+        ids::{ProjectId, ThreadId},
+        loaders::Loader,
+        projects::Color,
+    },
+    utils::{UrlJoinAll, UrlWithQuery},
+};
+use serde::de::IntoDeserializer;
+use std::{fmt::Display, str::FromStr};
 
-#[derive(Debug, Clone, Default)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq, Eq)]
 pub struct ExtendedSearch {
     pub offset: Option<u32>,
     pub facets: Vec<Vec<Facet>>,
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+/// A search query for [ModrinthAPI::search_with], built with chained calls
+///
+/// Facet groups are combined with AND, the facets inside a group with OR.
+///
+/// # Examples
+///
+/// ```
+/// use modrinth_api::structs::{
+///     projects::ProjectType,
+///     search::{Facet, SearchQuery, Sort},
+/// };
+///
+/// let query = SearchQuery::new("sodium")
+///     .sort(Sort::Downloads)
+///     .limit(50)
+///     .facet_and(Facet::ProjectType(ProjectType::Mod))
+///     .facet_or([
+///         Facet::Versions("1.20.1".into()),
+///         Facet::Versions("1.20.4".into()),
+///     ]);
+///
+/// assert!(query.url().is_ok());
+/// assert!(SearchQuery::new("sodium").limit(101).url().is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchQuery {
+    query: String,
+    sort: Sort,
+    limit: Option<u32>,
+    offset: Option<u32>,
+    facets: Vec<Vec<Facet>>,
+}
+
+impl SearchQuery {
+    /// Creates a query for `query`, sorted by relevance
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            query: query.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the search query string
+    pub fn query(mut self, query: impl Into<String>) -> Self {
+        self.query = query.into();
+        self
+    }
+
+    /// Sets the sorting criteria for the search results
+    pub fn sort(mut self, sort: Sort) -> Self {
+        self.sort = sort;
+        self
+    }
+
+    /// Sets the maximum number of results to return, between 1 and 100 (defaults to 20)
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the number of results to skip
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Adds a facet that every result must match
+    pub fn facet_and(mut self, facet: Facet) -> Self {
+        self.facets.push(vec![facet]);
+        self
+    }
+
+    /// Adds a group of facets of which every result must match at least one
+    pub fn facet_or(mut self, facets: impl IntoIterator<Item = Facet>) -> Self {
+        self.facets.push(facets.into_iter().collect());
+        self
+    }
+
+    /// Builds the URL of the search endpoint for this query
+    ///
+    /// Empty facet groups are left out.
+    ///
+    /// # Errors
+    ///
+    /// Returns [Error::InvalidSearchLimit] if the limit is not between 1 and 100.
+    pub fn url(&self) -> Result<Url> {
+        self.url_with_base(&BASE_URL)
+    }
+
+    /// [SearchQuery::url] relative to the API root `base_url`
+    pub(crate) fn url_with_base(&self, base_url: &Url) -> Result<Url> {
+        let limit = self.limit.unwrap_or(20);
+        if !(1..=100).contains(&limit) {
+            return Err(Error::InvalidSearchLimit(limit));
+        }
+
+        let url = base_url
+            .join_all(vec!["search"])
+            .with_query("query", &self.query)
+            .with_query("index", self.sort)
+            .with_query("limit", limit)
+            .with_query("offset", self.offset.unwrap_or(0));
+
+        let facets: Vec<_> = self.facets.iter().filter(|e| !e.is_empty()).collect();
+        if facets.is_empty() {
+            Ok(url)
+        } else {
+            Ok(url.with_query_json("facets", facets)?)
+        }
+    }
+}
+
+impl From<ExtendedSearch> for SearchQuery {
+    fn from(extended_search: ExtendedSearch) -> Self {
+        Self {
+            offset: extended_search.offset,
+            facets: extended_search.facets,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 pub struct Response {
     pub hits: Vec<SearchHit>,
     /// The number of results that were skipped by the query
@@ -24,7 +157,7 @@ impl Response {
     }
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 pub struct SearchHit {
     /// The project's slug, used for vanity URLs.
     pub slug: Option<String>,
@@ -37,23 +170,23 @@ pub struct SearchHit {
     pub downloads: usize,
     pub icon_url: Option<String>,
     /// The RGB color of the project, automatically generated from the project icon
-    pub color: Option<usize>,
+    pub color: Option<Color>,
     /// The ID of the moderation thread associated with this project
-    pub thread_id: Option<String>,
+    pub thread_id: Option<ThreadId>,
     pub monetization_status: Option<projects::MonetizationStatus>,
-    pub project_id: String,
+    pub project_id: ProjectId,
     /// Author
     pub author: String,
     /// A list of the project's primary/featured categories
     pub display_categories: Vec<String>,
     #[serde(rename = "versions")]
     /// A list of all the game versions supported by the project
-    pub game_versions: Vec<String>,
+    pub game_versions: Vec<GameVersion>,
     pub follows: usize,
     pub date_created: Date,
     pub date_modified: Date,
     /// The latest game version that this project supports
-    pub latest_version: String,
+    pub latest_version: GameVersion,
     /// The SPDX license ID of a project
     pub license: String,
     pub gallery: Vec<Url>,
@@ -86,7 +219,7 @@ impl SearchHit {
     ///   e.g., network issues, invalid project ID, rate limiting, or API response errors.
     ///
     pub async fn fetch_project(mut self, api: &ModrinthAPI) -> Result<Self> {
-        let result = api.get_project_by_id(self.project_id.as_str()).await?;
+        let result = api.get_project_by_id(&self.project_id).await?;
         self.project_info = Some(result);
         Ok(self)
     }
@@ -108,7 +241,7 @@ impl SearchHit {
     /// - `Ok(Project)`: The full [`Project`] data.
     /// - `Err(crate::error::Error)`: An error occurred during the API request or data processing.
     pub async fn get_full_project(&self, api: &ModrinthAPI) -> Result<Project> {
-        api.get_project_by_id(self.project_id.as_str()).await
+        api.get_project_by_id(&self.project_id).await
     }
 }
 
@@ -125,8 +258,10 @@ impl Display for SearchHit {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
+#[serde(rename_all = "lowercase")]
 pub enum Sort {
+    #[default]
     Relevance,
     /// Sorts matches by downloads
     Downloads,
@@ -144,10 +279,58 @@ impl Display for Sort {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// A comparison operator of a [Facet]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Op {
+    /// `:` (or `=`)
+    Eq,
+    /// `!=`
+    Ne,
+    /// `>`
+    Gt,
+    /// `>=`
+    Ge,
+    /// `<`
+    Lt,
+    /// `<=`
+    Le,
+}
+
+impl Display for Op {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Op::Eq => ":",
+            Op::Ne => "!=",
+            Op::Gt => ">",
+            Op::Ge => ">=",
+            Op::Lt => "<",
+            Op::Le => "<=",
+        })
+    }
+}
+
+impl FromStr for Op {
+    type Err = Error;
+
+    fn from_str(operation: &str) -> Result<Self> {
+        match operation {
+            ":" | "=" => Ok(Op::Eq),
+            "!=" => Ok(Op::Ne),
+            ">" => Ok(Op::Gt),
+            ">=" => Ok(Op::Ge),
+            "<" => Ok(Op::Lt),
+            "<=" => Ok(Op::Le),
+            _ => Err(Error::InvalidFacet(format!(
+                "unknown operator `{operation}`"
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Facet {
     ProjectType(projects::ProjectType),
-    /// Mod loader or category to filter
+    /// Mod loader or category to filter, see also `From<Loader>`
     Categories(String),
     /// Game versions to filter
     Versions(String),
@@ -160,63 +343,390 @@ pub enum Facet {
     Author(String),
     /// Project ID
     ProjectID(String),
-    Custom {
-        /// The type of metadata to filter
-        _type: String,
-        /// The comparison to use
-        ///
-        /// Can be `=`/`:`, `!=`, `>`, `>=`, `<`, `<=`
-        operation: String,
-        /// The value to compare against
-        value: String,
-    },
+    /// Number of downloads
+    Downloads(Op, u64),
+    /// Number of followers
+    Follows(Op, u64),
+    /// Time of initial creation, sent as `created_timestamp` in unix seconds
+    DateCreated(Op, Date),
+    /// Time of the latest update, sent as `modified_timestamp` in unix seconds
+    DateModified(Op, Date),
+    /// Client side support
+    ClientSide(Op, projects::ProjectSupportRange),
+    /// Server side support
+    ServerSide(Op, projects::ProjectSupportRange),
+    /// Any other facet, created with [Facet::custom] or by parsing
+    Custom(CustomFacet),
+}
+
+/// A facet without a dedicated [Facet] variant, e.g. `color>=1000` or `open_source:maybe`
+///
+/// It can only be created with [Facet::custom] or [Facet::from_str], which return the dedicated
+/// variant instead whenever there is one. A facet therefore has a single representation and
+/// round-trips through `Display` and serde unchanged.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CustomFacet {
+    _type: String,
+    operation: String,
+    value: String,
+}
+
+impl CustomFacet {
+    /// The type of metadata to filter
+    pub fn facet_type(&self) -> &str {
+        &self._type
+    }
+
+    /// The comparison to use, one of `=`/`:`, `!=`, `>`, `>=`, `<`, `<=`
+    pub fn operation(&self) -> &str {
+        &self.operation
+    }
+
+    /// The value to compare against
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+/// Loaders are searched as categories, e.g. [Loader::Fabric] becomes `categories:fabric`
+impl From<Loader> for Facet {
+    fn from(loader: Loader) -> Self {
+        Facet::Categories(loader.to_string())
+    }
 }
 
+/// Serialises to the wire format (e.g. `downloads>=1000`), the same as `Display`
+///
+/// Wrap it in a [StructuredFacet] to serialise the structured form instead.
 impl Serialize for Facet {
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        let output = match self {
-            Facet::ProjectType(project_type) => {
-                format!("project_type:{project_type:?}",)
-            }
-            Facet::Categories(category) => format!("categories: {category}"),
-            Facet::Versions(version) => format!("versions: {version}"),
-            Facet::OpenSource(bool) => format!("open_source: {bool}"),
-            Facet::License(license_id) => format!("license: {license_id}"),
-            Facet::Title(title) => format!("title: {title}"),
-            Facet::Author(author) => format!("author: {author}"),
-            Facet::ProjectID(project_id) => format!("project_id: {project_id}"),
-            Facet::Custom {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserialises from the wire format (e.g. `"downloads>=1000"`) or from a structured form
+/// (e.g. `{ "type": "downloads", "operation": ">=", "value": 1000 }`)
+impl<'de> Deserialize<'de> for Facet {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Value {
+            String(String),
+            Number(serde_json::Number),
+            Bool(bool),
+        }
+
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Wire(String),
+            Structured {
+                #[serde(rename = "type")]
+                _type: String,
+                operation: String,
+                value: Value,
+            },
+        }
+
+        let facet = match Repr::deserialize(deserializer)? {
+            Repr::Wire(facet) => facet,
+            Repr::Structured {
                 _type,
                 operation,
                 value,
-            } => format!("{_type} {operation} {value}"),
+            } => match value {
+                Value::String(value) => format!("{_type}{operation}{value}"),
+                Value::Number(value) => format!("{_type}{operation}{value}"),
+                Value::Bool(value) => format!("{_type}{operation}{value}"),
+            },
+        };
+        facet.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// A [Facet] that serialises to the structured form instead of the wire format, e.g.
+/// `{ "type": "downloads", "operation": ">=", "value": 1000 }`
+///
+/// Counts, timestamps and `open_source` are written as JSON numbers and booleans,
+/// everything else as strings. Deserialises from both forms, like [Facet].
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(transparent)]
+pub struct StructuredFacet(pub Facet);
+
+impl From<Facet> for StructuredFacet {
+    fn from(facet: Facet) -> Self {
+        StructuredFacet(facet)
+    }
+}
+
+impl From<StructuredFacet> for Facet {
+    fn from(facet: StructuredFacet) -> Self {
+        facet.0
+    }
+}
+
+impl Serialize for StructuredFacet {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        use serde_json::Value;
+
+        let (_type, operation, value) = match &self.0 {
+            Facet::ProjectType(project_type) => (
+                "project_type",
+                Op::Eq.to_string(),
+                project_type.to_string().into(),
+            ),
+            Facet::Categories(category) => {
+                ("categories", Op::Eq.to_string(), category.as_str().into())
+            }
+            Facet::Versions(version) => ("versions", Op::Eq.to_string(), version.as_str().into()),
+            Facet::OpenSource(bool) => ("open_source", Op::Eq.to_string(), Value::Bool(*bool)),
+            Facet::License(license_id) => {
+                ("license", Op::Eq.to_string(), license_id.as_str().into())
+            }
+            Facet::Title(title) => ("title", Op::Eq.to_string(), title.as_str().into()),
+            Facet::Author(author) => ("author", Op::Eq.to_string(), author.as_str().into()),
+            Facet::ProjectID(project_id) => {
+                ("project_id", Op::Eq.to_string(), project_id.as_str().into())
+            }
+            Facet::Downloads(op, downloads) => ("downloads", op.to_string(), (*downloads).into()),
+            Facet::Follows(op, follows) => ("follows", op.to_string(), (*follows).into()),
+            Facet::DateCreated(op, date) => {
+                ("created_timestamp", op.to_string(), date.timestamp().into())
+            }
+            Facet::DateModified(op, date) => (
+                "modified_timestamp",
+                op.to_string(),
+                date.timestamp().into(),
+            ),
+            Facet::ClientSide(op, support) => {
+                ("client_side", op.to_string(), support.to_string().into())
+            }
+            Facet::ServerSide(op, support) => {
+                ("server_side", op.to_string(), support.to_string().into())
+            }
+            Facet::Custom(custom) => (
+                custom.facet_type(),
+                custom.operation().to_string(),
+                custom.value().into(),
+            ),
         };
-        serializer.collect_str(&output)
+
+        let mut state = serializer.serialize_struct("Facet", 3)?;
+        state.serialize_field("type", _type)?;
+        state.serialize_field("operation", &operation)?;
+        state.serialize_field("value", &value)?;
+        state.end()
     }
 }
 
 impl Display for Facet {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let str: String = match self {
-            Facet::ProjectType(project_type) => {
-                format!("project_type:{project_type:?}",)
+        let facet = match self {
+            Facet::ProjectType(project_type) => format!("project_type:{project_type}"),
+            Facet::Categories(category) => format!("categories:{category}"),
+            Facet::Versions(version) => format!("versions:{version}"),
+            Facet::OpenSource(bool) => format!("open_source:{bool}"),
+            Facet::License(license_id) => format!("license:{license_id}"),
+            Facet::Title(title) => format!("title:{title}"),
+            Facet::Author(author) => format!("author:{author}"),
+            Facet::ProjectID(project_id) => format!("project_id:{project_id}"),
+            Facet::Downloads(op, downloads) => format!("downloads{op}{downloads}"),
+            Facet::Follows(op, follows) => format!("follows{op}{follows}"),
+            Facet::DateCreated(op, date) => format!("created_timestamp{op}{}", date.timestamp()),
+            Facet::DateModified(op, date) => {
+                format!("modified_timestamp{op}{}", date.timestamp())
             }
-            Facet::Categories(category) => format!("categories: {category}"),
-            Facet::Versions(version) => format!("versions: {version}"),
-            Facet::OpenSource(bool) => format!("open_source: {bool}"),
-            Facet::License(license_id) => format!("license: {license_id}"),
-            Facet::Title(title) => format!("title: {title}"),
-            Facet::Author(author) => format!("author: {author}"),
-            Facet::ProjectID(project_id) => format!("project_id: {project_id}"),
-            Facet::Custom {
-                _type,
-                operation,
-                value,
-            } => format!("{_type} {operation} {value}"),
+            Facet::ClientSide(op, support) => format!("client_side{op}{support}"),
+            Facet::ServerSide(op, support) => format!("server_side{op}{support}"),
+            Facet::Custom(custom) => format!(
+                "{}{}{}",
+                custom.facet_type(),
+                custom.operation(),
+                custom.value()
+            ),
         };
-        write!(f, "{}", str)
+        f.write_str(&facet)
+    }
+}
+
+impl Facet {
+    /// Creates a facet from its type, operation and value, e.g. `("color", ">=", "1000")`
+    ///
+    /// This is the same as parsing `{_type}{operation}{value}` with [Facet::from_str], so the
+    /// dedicated variant is returned if there is one (e.g. [Facet::Downloads] for `downloads`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [Error::InvalidFacet] if the type, operation or value is malformed.
+    pub fn custom(_type: &str, operation: &str, value: &str) -> Result<Facet> {
+        format!("{_type}{operation}{value}").parse()
+    }
+
+    /// Parses a facet expression like `(categories:fabric OR categories:quilt) AND versions:1.20.1`
+    ///
+    /// Groups are separated by `AND` and combined with AND, the facets inside a group are
+    /// separated by `OR` and combined with OR. The keywords are case-insensitive, so they cannot
+    /// appear as words inside a value. Parentheses around a group cannot be nested and are
+    /// required if the expression mixes `AND` and `OR`: `a OR b AND c` is rejected instead of
+    /// guessing which operator binds tighter.
+    /// Each facet is parsed with [Facet::from_str].
+    ///
+    /// # Errors
+    ///
+    /// Returns [Error::InvalidFacet] if the expression or one of its facets is malformed.
+    pub fn parse_expression(expression: &str) -> Result<Vec<Vec<Facet>>> {
+        let groups = split_keyword(expression, "AND")?;
+        let mixed = groups.len() > 1;
+        groups
+            .into_iter()
+            .map(|group| {
+                let group = group.trim();
+                let (group, parenthesized) = match group.strip_prefix('(') {
+                    Some(inner) => (
+                        inner.strip_suffix(')').ok_or_else(|| {
+                            Error::InvalidFacet(format!("unclosed group `{group}`"))
+                        })?,
+                        true,
+                    ),
+                    None => (group, false),
+                };
+                if group.contains(['(', ')']) || split_keyword(group, "AND")?.len() > 1 {
+                    return Err(Error::InvalidFacet(format!(
+                        "unexpected nesting in `{group}`"
+                    )));
+                }
+                let facets = split_keyword(group, "OR")?;
+                if mixed && !parenthesized && facets.len() > 1 {
+                    return Err(Error::InvalidFacet(format!(
+                        "`{group}` mixes AND and OR, put it in parentheses"
+                    )));
+                }
+                facets.into_iter().map(Facet::from_str).collect()
+            })
+            .collect()
+    }
+}
+
+/// Splits `input` on `keyword` (in any case) surrounded by whitespace, outside of parentheses
+fn split_keyword<'a>(input: &'a str, keyword: &str) -> Result<Vec<&'a str>> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+
+    for (index, char) in input.char_indices() {
+        match char {
+            '(' => depth += 1,
+            ')' => {
+                depth = depth.checked_sub(1).ok_or_else(|| {
+                    Error::InvalidFacet(format!("unbalanced parenthesis in `{input}`"))
+                })?
+            }
+            _ if depth == 0 && char.is_whitespace() => {
+                let rest = &input[index + char.len_utf8()..];
+                if rest
+                    .get(..keyword.len())
+                    .is_some_and(|word| word.eq_ignore_ascii_case(keyword))
+                    && rest[keyword.len()..].starts_with(char::is_whitespace)
+                {
+                    parts.push(&input[start..index]);
+                    start = index + char.len_utf8() + keyword.len();
+                }
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        return Err(Error::InvalidFacet(format!(
+            "unbalanced parenthesis in `{input}`"
+        )));
+    }
+    parts.push(&input[start..]);
+    Ok(parts)
+}
+
+impl FromStr for Facet {
+    type Err = Error;
+
+    /// Parses a single facet like `categories:fabric`, `versions!=1.20.1` or `downloads>=1000`
+    ///
+    /// Whitespace around the operator is ignored. Facets on known metadata are parsed into
+    /// their dedicated variant (dates as unix seconds or RFC 3339), everything else, including
+    /// known metadata with a value that does not fit the variant (e.g. `open_source:maybe`),
+    /// into [Facet::Custom].
+    fn from_str(facet: &str) -> Result<Self> {
+        let invalid = || Error::InvalidFacet(facet.to_string());
+
+        let facet = facet.trim();
+        let key_end = facet
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .ok_or_else(invalid)?;
+        let (key, rest) = facet.split_at(key_end);
+        let rest = rest.trim_start();
+        let operation = ["!=", ">=", "<=", ":", "=", ">", "<"]
+            .into_iter()
+            .find(|operation| rest.starts_with(operation))
+            .ok_or_else(invalid)?;
+        let value = rest[operation.len()..].trim();
+        if key.is_empty() || value.is_empty() {
+            return Err(invalid());
+        }
+
+        let op: Op = operation.parse()?;
+        let parse_date = |value: &str| {
+            value
+                .parse()
+                .ok()
+                .and_then(|seconds| Date::from_timestamp(seconds, 0))
+                .or_else(|| value.parse().ok())
+        };
+        let parse_support = |value: &str| {
+            projects::ProjectSupportRange::deserialize(value.to_lowercase().into_deserializer())
+                .map_err(|_: serde::de::value::Error| ())
+                .ok()
+        };
+        let typed = match (key, op) {
+            ("downloads", _) => value.parse().ok().map(|v| Facet::Downloads(op, v)),
+            ("follows", _) => value.parse().ok().map(|v| Facet::Follows(op, v)),
+            ("created_timestamp" | "date_created", _) => {
+                parse_date(value).map(|date| Facet::DateCreated(op, date))
+            }
+            ("modified_timestamp" | "date_modified", _) => {
+                parse_date(value).map(|date| Facet::DateModified(op, date))
+            }
+            ("client_side", _) => parse_support(value).map(|s| Facet::ClientSide(op, s)),
+            ("server_side", _) => parse_support(value).map(|s| Facet::ServerSide(op, s)),
+            ("project_type", Op::Eq) => {
+                projects::ProjectType::deserialize(value.to_lowercase().into_deserializer())
+                    .map_err(|_: serde::de::value::Error| ())
+                    .ok()
+                    .map(Facet::ProjectType)
+            }
+            ("categories", Op::Eq) => Some(Facet::Categories(value.to_string())),
+            ("versions", Op::Eq) => Some(Facet::Versions(value.to_string())),
+            ("open_source", Op::Eq) => value.parse().ok().map(Facet::OpenSource),
+            ("license", Op::Eq) => Some(Facet::License(value.to_string())),
+            ("title", Op::Eq) => Some(Facet::Title(value.to_string())),
+            ("author", Op::Eq) => Some(Facet::Author(value.to_string())),
+            ("project_id", Op::Eq) => Some(Facet::ProjectID(value.to_string())),
+            _ => None,
+        };
+
+        Ok(typed.unwrap_or_else(|| {
+            Facet::Custom(CustomFacet {
+                _type: key.to_string(),
+                operation: operation.to_string(),
+                value: value.to_string(),
+            })
+        }))
     }
 }
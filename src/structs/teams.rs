@@ -0,0 +1,94 @@
+//! Models related to teams
+//!
+//! [documentation](https://docs.modrinth.com/api/operations/tags/teams/)
+
+use super::*;
+use crate::structs::ids::TeamId;
+use crate::structs::users::User;
+use serde::{Deserializer, Serializer};
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct TeamMember {
+    /// The ID of the team this team member is a member of
+    pub team_id: TeamId,
+    pub user: User,
+    /// The user's role on the team
+    pub role: String,
+    /// The user's permissions, only visible to members of the team
+    pub permissions: Option<ProjectPermissions>,
+    /// Whether or not the user has accepted to be on the team
+    pub accepted: bool,
+    /// The split of payouts going to this user, only visible to members of the team
+    pub payouts_split: Option<f64>,
+    /// The order of the team member
+    pub ordering: Option<i64>,
+}
+
+bitflags::bitflags! {
+    /// The permissions of a [TeamMember] on a project, sent as an integer bitfield
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ProjectPermissions: u64 {
+        const UPLOAD_VERSION = 1 << 0;
+        const DELETE_VERSION = 1 << 1;
+        const EDIT_DETAILS = 1 << 2;
+        const EDIT_BODY = 1 << 3;
+        const MANAGE_INVITES = 1 << 4;
+        const REMOVE_MEMBER = 1 << 5;
+        const EDIT_MEMBER = 1 << 6;
+        const DELETE_PROJECT = 1 << 7;
+        const VIEW_ANALYTICS = 1 << 8;
+        const VIEW_PAYOUTS = 1 << 9;
+    }
+}
+
+bitflags::bitflags! {
+    /// The permissions of a team member on an organization, sent as an integer bitfield
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct OrganizationPermissions: u64 {
+        const EDIT_DETAILS = 1 << 0;
+        const MANAGE_INVITES = 1 << 1;
+        const REMOVE_MEMBER = 1 << 2;
+        const EDIT_MEMBER = 1 << 3;
+        const ADD_PROJECT = 1 << 4;
+        const REMOVE_PROJECT = 1 << 5;
+        const DELETE_ORGANIZATION = 1 << 6;
+        const EDIT_MEMBER_DEFAULT_PERMISSIONS = 1 << 7;
+    }
+}
+
+/// (De)serialises a bitflags type as its integer value, which is what the API uses
+macro_rules! impl_bits_serde {
+    ($($flags:ty),*) => {$(
+        impl Serialize for $flags {
+            fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+                serializer.serialize_u64(self.bits())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $flags {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+                // Unknown bits are kept so that they survive a round trip
+                Ok(Self::from_bits_retain(u64::deserialize(deserializer)?))
+            }
+        }
+    )*};
+}
+
+impl_bits_serde!(ProjectPermissions, OrganizationPermissions);
+
+/// Body of [ModrinthAPI::modify_team_member], only the fields set to `Some` are changed
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct EditTeamMember {
+    /// The user's role on the team
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+    /// The user's permissions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<ProjectPermissions>,
+    /// The split of payouts going to this user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payouts_split: Option<f64>,
+    /// The order of the team member
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ordering: Option<i64>,
+}
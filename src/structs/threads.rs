@@ -0,0 +1,95 @@
+//! Models related to threads
+//!
+//! [documentation](https://docs.modrinth.com/api/operations/tags/threads/)
+
+use super::*;
+use crate::structs::{
+    ids::{ProjectId, ReportId, ThreadId, ThreadMessageId, UserId},
+    projects::ProjectStatus,
+    users::User,
+};
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct Thread {
+    /// The ID of the thread
+    pub id: ThreadId,
+    /// What the thread is attached to
+    #[serde(rename = "type")]
+    pub thread_type: ThreadType,
+    /// The ID of the associated project if a project thread
+    pub project_id: Option<ProjectId>,
+    /// The ID of the associated report if a report thread
+    pub report_id: Option<ReportId>,
+    /// The messages of the thread, oldest first
+    pub messages: Vec<ThreadMessage>,
+    /// The users participating in the thread
+    pub members: Vec<User>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ThreadType {
+    Project,
+    Report,
+    DirectMessage,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct ThreadMessage {
+    /// The ID of the message itself
+    pub id: ThreadMessageId,
+    /// The ID of the author, if the identity is not hidden
+    pub author_id: Option<UserId>,
+    /// The contents of the message
+    pub body: MessageBody,
+    /// The time at which the message was created
+    pub created: Date,
+    /// Whether the author is hidden behind the moderation team
+    #[serde(default)]
+    pub hide_identity: bool,
+}
+
+/// The contents of a [ThreadMessage], tagged by its `type`
+///
+/// Types not known to this crate are deserialised as [MessageBody::Unknown].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MessageBody {
+    /// A text message
+    Text {
+        /// The actual message text
+        body: String,
+        /// Whether the message is only visible to moderators
+        #[serde(default)]
+        private: bool,
+        /// The ID of the message being replied to
+        replying_to: Option<ThreadMessageId>,
+    },
+    /// The status of the project was changed
+    StatusChange {
+        new_status: ProjectStatus,
+        old_status: ProjectStatus,
+    },
+    /// The thread was closed
+    ThreadClosure,
+    /// The thread was reopened
+    ThreadReopen,
+    /// The message was deleted
+    Deleted {
+        #[serde(default)]
+        private: bool,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+impl MessageBody {
+    /// Creates a public text message, as sent by [ModrinthAPI::send_thread_message]
+    pub fn text(body: impl Into<String>) -> Self {
+        Self::Text {
+            body: body.into(),
+            private: false,
+            replying_to: None,
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! Models related to users
+//!
+//! [documentation](https://docs.modrinth.com/api/operations/tags/users/)
+
+use super::*;
+use crate::structs::ids::UserId;
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct User {
+    /// The user's username
+    pub username: String,
+    /// The user's display name
+    pub name: Option<String>,
+    /// The user's email (only displayed if requesting your own account)
+    pub email: Option<String>,
+    /// A description of the user
+    pub bio: Option<String>,
+    /// The user's ID
+    pub id: UserId,
+    /// The user's avatar url
+    pub avatar_url: Option<String>,
+    /// The time at which the user was created
+    pub created: Date,
+    /// The user's role
+    pub role: UserRole,
+    /// Any badges applicable to this user, as a bitfield
+    pub badges: u64,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum UserRole {
+    Admin,
+    Moderator,
+    Developer,
+}
+
+/// Body of [ModrinthAPI::modify_user], only the fields set to `Some` are changed
+///
+/// The optional fields of the profile are cleared by setting them to `Some(None)`.
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct EditUser {
+    /// The user's username
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    /// The user's display name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<Option<String>>,
+    /// The user's email
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<Option<String>>,
+    /// A description of the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bio: Option<Option<String>>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct PayoutHistory {
+    /// The all-time balance accrued by this user in USD
+    pub all_time: String,
+    /// The amount in USD made by the user in the previous 30 days
+    pub last_month: String,
+    /// A history of all of the user's past transactions
+    pub payouts: Vec<Payout>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct Payout {
+    /// The date of this transaction
+    pub created: Date,
+    /// The amount of this transaction in USD
+    pub amount: f64,
+    /// The status of this transaction
+    pub status: String,
+}
@@ -0,0 +1,93 @@
+//! v3 models related to analytics
+//!
+//! All analytics are keyed by project ID, then by a point in time (or a country).
+
+use super::*;
+use serde::{Deserializer, Serializer};
+use std::collections::BTreeMap;
+
+/// Time series of every requested project, keyed by project ID
+pub type Analytics<T> = HashMap<String, TimeSeries<T>>;
+
+/// Totals by ISO country code of every requested project, keyed by project ID
+pub type CountryAnalytics = HashMap<String, HashMap<String, u64>>;
+
+/// Values of a single project ordered by the start of each time slice
+///
+/// The API sends the timestamps as unix seconds in the keys of a JSON object.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TimeSeries<T>(pub BTreeMap<Date, T>);
+
+impl<T> TimeSeries<T> {
+    /// Iterates over the time slices in chronological order
+    pub fn iter(&self) -> impl Iterator<Item = (&Date, &T)> {
+        self.0.iter()
+    }
+}
+
+impl<T: Serialize> Serialize for TimeSeries<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_map(self.0.iter().map(|(date, value)| (date.timestamp(), value)))
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for TimeSeries<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        HashMap::<String, T>::deserialize(deserializer)?
+            .into_iter()
+            .map(|(timestamp, value)| {
+                timestamp
+                    .parse()
+                    .ok()
+                    .and_then(|seconds| Date::from_timestamp(seconds, 0))
+                    .map(|date| (date, value))
+                    .ok_or_else(|| {
+                        serde::de::Error::custom(format!("invalid timestamp `{timestamp}`"))
+                    })
+            })
+            .collect::<std::result::Result<_, _>>()
+            .map(Self)
+    }
+}
+
+/// An amount of revenue in USD
+///
+/// The API sends decimals either as JSON numbers or as strings, both are accepted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd, Serialize)]
+pub struct Revenue(pub f64);
+
+impl<'de> Deserialize<'de> for Revenue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Decimal {
+            Number(f64),
+            String(String),
+        }
+
+        match Decimal::deserialize(deserializer)? {
+            Decimal::Number(amount) => Ok(Self(amount)),
+            Decimal::String(amount) => amount.parse().map(Self).map_err(serde::de::Error::custom),
+        }
+    }
+}
+
+/// Extra parameters for the analytics functions of [ModrinthAPIv3](crate::api::v3::ModrinthAPIv3)
+///
+/// Fields of `AnalyticsFilter`:
+///
+///   - `project_ids`: `Option<&[&str]>` - The projects to get analytics for.
+///     If `None`, all projects of the authenticated user are used.
+///
+///   - `start_date` / `end_date`: `Option<Date>` - The time range to get analytics for.
+///     Defaults to the last two weeks.
+///
+///   - `resolution_minutes`: `Option<u32>` - The length of each time slice in minutes.
+///     Defaults to one day (`1440`).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AnalyticsFilter<'a> {
+    pub project_ids: Option<&'a [&'a str]>,
+    pub start_date: Option<Date>,
+    pub end_date: Option<Date>,
+    pub resolution_minutes: Option<u32>,
+}
@@ -0,0 +1,71 @@
+//! v3 models related to collections
+
+use super::*;
+use crate::structs::{
+    ids::{CollectionId, ProjectId, UserId},
+    projects::Color,
+};
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct Collection {
+    /// The ID of the collection
+    pub id: CollectionId,
+    /// The ID of the user who owns the collection
+    pub user: UserId,
+    /// The name of the collection
+    pub name: String,
+    /// A description of the collection
+    pub description: Option<String>,
+    /// Collection icon URL
+    pub icon_url: Option<String>,
+    /// The RGB color of the collection, automatically generated from the icon
+    pub color: Option<Color>,
+    /// The visibility of the collection
+    pub status: CollectionStatus,
+    /// The time at which the collection was created
+    pub created: Date,
+    /// The time at which the collection was last updated
+    pub updated: Date,
+    /// The IDs of the projects in the collection
+    pub projects: Vec<ProjectId>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum CollectionStatus {
+    Listed,
+    Unlisted,
+    Private,
+    Rejected,
+    Unknown,
+}
+
+/// Body of [ModrinthAPIv3::create_collection](crate::api::v3::ModrinthAPIv3::create_collection)
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct CreateCollection {
+    /// The name of the collection
+    pub name: String,
+    /// A description of the collection
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The IDs of the projects in the collection
+    pub projects: Vec<ProjectId>,
+}
+
+/// Body of [ModrinthAPIv3::modify_collection](crate::api::v3::ModrinthAPIv3::modify_collection),
+/// only the fields set to `Some` are changed
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct EditCollection {
+    /// The name of the collection
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// A description of the collection
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The visibility of the collection
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<CollectionStatus>,
+    /// The IDs of the projects in the collection, replacing the current list
+    #[serde(rename = "new_projects", skip_serializing_if = "Option::is_none")]
+    pub projects: Option<Vec<ProjectId>>,
+}
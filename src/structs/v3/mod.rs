@@ -0,0 +1,19 @@
+//! Models of the Labrinth v3 API
+//!
+//! Enums and small structs that did not change between v2 and v3 are reused from the v2 modules.
+
+pub mod analytics;
+pub mod collections;
+pub mod organizations;
+pub mod projects;
+pub mod teams;
+pub mod versions;
+
+use super::Date;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use url::Url;
+
+/// Values of the loader fields (e.g. `game_versions`, `client_side`, `mrpack_loaders`),
+/// keyed by field name. Which fields exist depends on the project type and loaders.
+pub type LoaderFields = HashMap<String, serde_json::Value>;
@@ -0,0 +1,54 @@
+//! v3 models related to organizations
+//!
+//! [documentation](https://docs.modrinth.com/api/operations/tags/organizations/)
+
+use super::*;
+use crate::structs::ids::{OrganizationId, TeamId};
+use crate::structs::projects::Color;
+use crate::structs::v3::teams::TeamMember;
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct Organization {
+    /// The ID of the organization
+    pub id: OrganizationId,
+    /// The slug of the organization, used for vanity URLs
+    pub slug: String,
+    /// The display name of the organization
+    pub name: String,
+    /// The ID of the team that has ownership of this organization
+    pub team_id: TeamId,
+    /// A description of the organization
+    pub description: String,
+    /// Organization icon URL
+    pub icon_url: Option<String>,
+    /// The RGB color of the organization, automatically generated from the icon
+    pub color: Option<Color>,
+    /// The members of the organization
+    pub members: Vec<TeamMember>,
+}
+
+/// Body of [ModrinthAPIv3::create_organization](crate::api::v3::ModrinthAPIv3::create_organization)
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct CreateOrganization {
+    /// The slug of the organization, used for vanity URLs
+    pub slug: String,
+    /// The display name of the organization
+    pub name: String,
+    /// A description of the organization
+    pub description: String,
+}
+
+/// Body of [ModrinthAPIv3::modify_organization](crate::api::v3::ModrinthAPIv3::modify_organization),
+/// only the fields set to `Some` are changed
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct EditOrganization {
+    /// The slug of the organization, used for vanity URLs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    /// The display name of the organization
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// A description of the organization
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
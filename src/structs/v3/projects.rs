@@ -0,0 +1,89 @@
+//! v3 models related to projects
+//!
+//! [documentation](https://docs.modrinth.com/api/operations/tags/projects/)
+
+use super::*;
+use crate::structs::ids::{OrganizationId, ProjectId, TeamId, ThreadId, VersionId};
+use crate::structs::loaders::Loader;
+use crate::structs::projects::{
+    Color, License, MonetizationStatus, ProjectStatus, RequestedStatus,
+};
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub struct Project {
+    pub id: ProjectId,
+    /// The slug of a project, used for vanity URLs
+    pub slug: Option<String>,
+    /// The project types of the project (e.g. `mod`, `modpack`), derived from its versions
+    pub project_types: Vec<String>,
+    /// The games the project supports (e.g. `minecraft-java`)
+    pub games: Vec<String>,
+    /// The ID of the team that has ownership of this project
+    pub team_id: TeamId,
+    /// The ID of the organization that owns this project, if any
+    pub organization: Option<OrganizationId>,
+    /// Title of the project
+    pub name: String,
+    /// A short description of the project
+    pub summary: String,
+    /// A long form description of the project
+    pub description: String,
+    pub published: Date,
+    pub updated: Date,
+    /// The date the project's status was approved
+    pub approved: Option<Date>,
+    pub queued: Option<Date>,
+    /// The status of the project
+    pub status: ProjectStatus,
+    /// The requested status when submitting for review or scheduling the project for release
+    pub requested_status: Option<RequestedStatus>,
+    pub license: License,
+    pub downloads: usize,
+    pub followers: usize,
+    /// A list of the categories that the project has
+    pub categories: Vec<String>,
+    /// A list of categories which are searchable but non-primary
+    pub additional_categories: Vec<String>,
+    /// A list of all the loaders supported by the project
+    pub loaders: Vec<Loader>,
+    /// A list of the version IDs of the project (will never be empty unless draft status)
+    pub versions: Vec<VersionId>,
+    /// Project icon URL
+    pub icon_url: Option<String>,
+    /// Links to external resources (issues, source, wiki, discord, donations...), keyed by kind
+    pub link_urls: HashMap<String, Link>,
+    pub gallery: Vec<GalleryItem>,
+    /// The RGB color of the project, automatically generated from the project icon
+    pub color: Option<Color>,
+    /// The ID of the moderation thread associated with this project
+    pub thread_id: ThreadId,
+    pub monetization_status: MonetizationStatus,
+    /// The loader fields of all versions of the project, aggregated
+    ///
+    /// Most values are arrays, but this also collects any other field unknown to this crate.
+    #[serde(flatten)]
+    pub fields: LoaderFields,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub struct Link {
+    pub platform: String,
+    /// Whether this is a donation link
+    pub donation: bool,
+    pub url: Url,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub struct GalleryItem {
+    /// The URL of the (compressed) gallery image
+    pub url: Url,
+    /// The URL of the original gallery image
+    pub raw_url: Url,
+    /// Whether the image is featured in the gallery
+    pub featured: bool,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub created: Date,
+    /// The order of the gallery image, images are sorted by this (from least to greatest)
+    pub ordering: i64,
+}
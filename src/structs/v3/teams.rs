@@ -0,0 +1,29 @@
+//! v3 models related to teams
+
+use super::*;
+use crate::structs::ids::TeamId;
+use crate::structs::{
+    teams::{OrganizationPermissions, ProjectPermissions},
+    users::User,
+};
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct TeamMember {
+    /// The ID of the team this team member is a member of
+    pub team_id: TeamId,
+    pub user: User,
+    /// The user's role on the team
+    pub role: String,
+    /// Whether the user is the owner of the team
+    pub is_owner: bool,
+    /// The user's permissions on projects, only visible to members of the team
+    pub permissions: Option<ProjectPermissions>,
+    /// The user's permissions on the organization, only set for organization teams
+    pub organization_permissions: Option<OrganizationPermissions>,
+    /// Whether or not the user has accepted to be on the team
+    pub accepted: bool,
+    /// The split of payouts going to this user, only visible to members of the team
+    pub payouts_split: Option<f64>,
+    /// The order of the team member
+    pub ordering: Option<i64>,
+}
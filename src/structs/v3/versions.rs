@@ -0,0 +1,34 @@
+//! v3 models related to versions
+
+use super::*;
+use crate::structs::ids::{ProjectId, UserId, VersionId};
+use crate::structs::loaders::Loader;
+use crate::structs::versions::{Dependencies, File, RequestedStatus, Status, VersionType};
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub struct Version {
+    pub id: VersionId,
+    pub project_id: ProjectId,
+    pub author_id: UserId,
+    pub featured: bool,
+    pub name: String,
+    pub version_number: String,
+    /// The project types of the project this version belongs to
+    pub project_types: Vec<String>,
+    /// The games this version supports
+    pub games: Vec<String>,
+    pub changelog: String,
+    pub date_published: Date,
+    pub downloads: usize,
+    pub version_type: VersionType,
+    pub status: Status,
+    pub requested_status: Option<RequestedStatus>,
+    pub files: Vec<File>,
+    pub dependencies: Vec<Dependencies>,
+    pub loaders: Vec<Loader>,
+    /// The order of the version, versions are sorted by this before the publishing date
+    pub ordering: Option<i64>,
+    /// The loader fields of this version (e.g. `game_versions`, `environment`)
+    #[serde(flatten)]
+    pub fields: LoaderFields,
+}
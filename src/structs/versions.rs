@@ -1,24 +1,31 @@
 use super::*;
+use crate::Error;
+use crate::structs::{
+    game_versions::GameVersion,
+    ids::{ProjectId, UserId, VersionId},
+    loaders::Loader,
+};
 use serde::{Deserialize, Serialize};
+use std::{fmt::Display, str::FromStr};
 
 /// The Version struct
 ///
 /// Documentation: <https://docs.modrinth.com/api/operations/getprojectversions/#200>
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct Version {
     pub name: String,
     pub version_number: String,
     pub changelog: Option<String>,
     pub dependencies: Vec<Dependencies>,
-    pub game_versions: Vec<String>,
+    pub game_versions: Vec<GameVersion>,
     pub version_type: VersionType,
-    pub loaders: Vec<String>,
+    pub loaders: Vec<Loader>,
     pub featured: bool,
     pub status: Option<Status>,
     pub requested_status: Option<RequestedStatus>,
-    pub id: String,
-    pub project_id: String,
-    pub author_id: String,
+    pub id: VersionId,
+    pub project_id: ProjectId,
+    pub author_id: UserId,
     pub date_published: Date,
     pub downloads: usize,
     pub files: Vec<File>,
@@ -28,93 +35,57 @@ pub struct Version {
 ///
 /// Fields of `ProjectVersionsFilter`:
 ///
-///   - `loaders`: `Option<&[&str]>` - A slice of loader IDs (e.g., `&["forge", "fabric"]`)
-///     to filter the list of versions. This is only applied if `number` is `None`.
-///     Example: `loaders: Some(&["fabric", "quilt"])`
+///   - `loaders`: `Option<&[Loader]>` - A slice of loaders (e.g., `&[Loader::Forge, Loader::Fabric]`)
+///     to filter the list of versions.
+///     Example: `loaders: Some(&[Loader::Fabric, Loader::Quilt])`
 ///
 ///   - `game_versions`: `Option<&[&str]>` - A slice of game version IDs (e.g., `&["1.19.2", "1.20.1"]`)
-///     to filter the list of versions. This is only applied if `number` is `None`.
+///     to filter the list of versions.
 ///     Example: `game_versions: Some(&["1.20.1"])`
 ///
 ///   - `featured`: `Option<bool>` - If `Some(true)`, only featured versions will be returned.
 ///     If `Some(false)`, featured versions will be excluded. If `None`, both featured and
-///     non-featured versions are included. This is only applied if `number` is `None`.
+///     non-featured versions are included.
 ///     Example: `featured: Some(true)`
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct ProjectVersionsFilter<'a> {
-    pub loaders: Option<&'a [&'a str]>,
+    pub loaders: Option<&'a [Loader]>,
     pub game_versions: Option<&'a [&'a str]>,
     pub featured: Option<bool>,
 }
 
-/// Extra parameters for [ModrinthAPI::get_project_version] function
-///
-/// Fields
-///
-///   - `number`: `Option<&str>` - Specific version ID or a version number string (e.g., "1.0.0").
-///     Example: `number: Some("0.76.0+1.19.2")`
-///
-///   - `loaders`: `Option<&[&str]>` - A slice of loader IDs (e.g., `&["forge", "fabric"]`)
-///     to filter the list of versions. This is only applied if `number` is `None`.
-///     Example: `loaders: Some(&["fabric", "quilt"])`
-///
-///   - `game_versions`: `Option<&[&str]>` - A slice of game version IDs (e.g., `&["1.19.2", "1.20.1"]`)
-///     to filter the list of versions. This is only applied if `number` is `None`.
-///     Example: `game_versions: Some(&["1.20.1"])`
-///
-///   - `featured`: `Option<bool>` - If `Some(true)`, only featured versions will be returned.
-///     If `Some(false)`, featured versions will be excluded. If `None`, both featured and
-///     non-featured versions are included. This is only applied if `number` is `None`.
-///     Example: `featured: Some(true)`
-#[derive(Debug)]
-pub struct ProjectVersionParams<'a> {
-    /// Get a version given a version number or ID
-    ///
-    /// Note:
-    ///   * if the version number provided matches multiple versions, only the oldest matching version will be returned.
-    pub number: Option<&'a str>,
-    pub loaders: Option<&'a [&'a str]>,
-    pub game_versions: Option<&'a [&'a str]>,
-    pub featured: Option<bool>,
-}
-
-impl<'a> Default for ProjectVersionParams<'a> {
-    fn default() -> Self {
-        ProjectVersionParams {
-            number: Some(""),
-            loaders: None,
-            game_versions: None,
-            featured: None,
-        }
-    }
-}
-
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct Dependencies {
-    pub version_id: Option<String>,
-    pub project_id: Option<String>,
+    pub version_id: Option<VersionId>,
+    pub project_id: Option<ProjectId>,
     pub file_name: Option<String>,
     pub dependency_type: DependencyType,
 }
 
-#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
 #[serde(rename_all = "lowercase")]
 pub enum DependencyType {
     Required,
     Optional,
     Incompatible,
     Embedded,
+    /// A value not known to this crate
+    #[serde(untagged)]
+    Other(String),
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
 #[serde(rename_all = "lowercase")]
 pub enum VersionType {
     Release,
     Beta,
     Alpha,
+    /// A value not known to this crate
+    #[serde(untagged)]
+    Other(String),
 }
 
-#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
 #[serde(rename_all = "lowercase")]
 pub enum Status {
     Listed,
@@ -123,9 +94,12 @@ pub enum Status {
     Unlisted,
     Scheduled,
     Unknown,
+    /// A value not known to this crate
+    #[serde(untagged)]
+    Other(String),
 }
 
-#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[serde(rename_all = "lowercase")]
 pub enum RequestedStatus {
     Listed,
@@ -134,7 +108,7 @@ pub enum RequestedStatus {
     Unlisted,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct File {
     pub hashes: Hash,
     pub url: String,
@@ -144,15 +118,90 @@ pub struct File {
     pub file_type: Option<FileType>,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct Hash {
-    pub sha512: String,
-    pub sha1: String,
+    pub sha512: Sha512,
+    pub sha1: Sha1,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+macro_rules! hash_type {
+    ($(#[$meta:meta])* $name:ident, $len:literal, $error:ident) => {
+        $(#[$meta])*
+        #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+        #[serde(try_from = "String", into = "String")]
+        pub struct $name(String);
+
+        impl $name {
+            /// Returns the lowercase hexadecimal representation of the hash
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            /// Creates the hash from the raw digest bytes
+            pub(crate) fn from_digest(digest: &[u8]) -> Self {
+                Self(digest.iter().map(|byte| format!("{byte:02x}")).collect())
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = Error;
+
+            fn try_from(mut hash: String) -> Result<Self> {
+                if hash.len() != $len || !hash.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+                    return Err(Error::$error);
+                }
+                hash.make_ascii_lowercase();
+                Ok(Self(hash))
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Error;
+
+            fn from_str(hash: &str) -> Result<Self> {
+                hash.to_string().try_into()
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(hash: $name) -> Self {
+                hash.0
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+    };
+}
+
+hash_type!(
+    /// A SHA1 hash as 40 hexadecimal characters
+    Sha1,
+    40,
+    InvalidSHA1
+);
+hash_type!(
+    /// A SHA512 hash as 128 hexadecimal characters
+    Sha512,
+    128,
+    InvalidSHA512
+);
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
 #[serde(rename_all = "kebab-case")]
 pub enum FileType {
     RequiredResourcePack,
     OptionalResourcePack,
+    /// A value not known to this crate
+    #[serde(untagged)]
+    Other(String),
 }
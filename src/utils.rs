@@ -1,8 +1,12 @@
+use crate::structs::versions::{Hash, Sha1, Sha512};
 use crate::{Error, Result};
 use lazy_regex::regex_is_match;
 use reqwest::{RequestBuilder, Response, StatusCode};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
+use sha1::Digest;
+use std::path::Path;
+use tokio::io::{AsyncRead, AsyncReadExt};
 use url::Url;
 
 pub fn check_id_slug<S: AsRef<str>>(inputs: &[S]) -> Result<()> {
@@ -14,6 +18,42 @@ pub fn check_id_slug<S: AsRef<str>>(inputs: &[S]) -> Result<()> {
     })
 }
 
+/// The `Content-Type` of an image upload with the file extension `ext`, e.g. `image/png`
+pub(crate) fn image_content_type(ext: &str) -> String {
+    match ext.to_lowercase().as_str() {
+        "jpg" => "image/jpeg".to_string(),
+        "svg" => "image/svg+xml".to_string(),
+        ext => format!("image/{ext}"),
+    }
+}
+
+/// Computes the SHA1 and SHA512 hashes of everything read from `reader`, in one pass
+///
+/// The result can be compared with [`File::hashes`](crate::structs::versions::File::hashes)
+/// to verify a download.
+pub async fn hash_reader<R: AsyncRead + Unpin>(mut reader: R) -> Result<Hash> {
+    let mut sha1 = sha1::Sha1::new();
+    let mut sha512 = sha2::Sha512::new();
+    let mut buffer = vec![0; 64 * 1024];
+    loop {
+        let read = reader.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        sha1.update(&buffer[..read]);
+        sha512.update(&buffer[..read]);
+    }
+    Ok(Hash {
+        sha512: Sha512::from_digest(&sha512.finalize()),
+        sha1: Sha1::from_digest(&sha1.finalize()),
+    })
+}
+
+/// Computes the SHA1 and SHA512 hashes of the file at `path`, see [hash_reader]
+pub async fn hash_file(path: impl AsRef<Path>) -> Result<Hash> {
+    hash_reader(tokio::fs::File::open(path).await?).await
+}
+
 // From: https://github.com/gorilla-devs/ferinth/blob/master/src/request.rs
 pub(crate) trait RequestBuilderCustomSend {
     /// Build and send `self`, and return the response
@@ -21,6 +61,9 @@ pub(crate) trait RequestBuilderCustomSend {
 
     /// Build and send `self`, and deserialise the response to `T` and return it
     async fn custom_send_json<T: DeserializeOwned>(self) -> Result<T>;
+
+    /// Like [RequestBuilderCustomSend::custom_send_json], but returns `None` if the response is a 404
+    async fn custom_send_json_optional<T: DeserializeOwned>(self) -> Result<Option<T>>;
 }
 
 impl RequestBuilderCustomSend for RequestBuilder {
@@ -32,6 +75,15 @@ impl RequestBuilderCustomSend for RequestBuilder {
         let bytes = self.custom_send().await?.bytes().await?;
         Ok(serde_json::from_slice(&bytes)?)
     }
+
+    async fn custom_send_json_optional<T: DeserializeOwned>(self) -> Result<Option<T>> {
+        let response = check_rate_limit(self.send().await?)?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let bytes = response.error_for_status()?.bytes().await?;
+        Ok(Some(serde_json::from_slice(&bytes)?))
+    }
 }
 
 // From: https://github.com/gorilla-devs/ferinth/blob/master/src/request.rs
@@ -0,0 +1,114 @@
+#![cfg(feature = "v3")]
+
+mod common;
+
+use modrinth_api::structs::v3::collections::{
+    Collection, CollectionStatus, CreateCollection, EditCollection,
+};
+use wiremock::matchers::{body_json, method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+const COLLECTION_FIXTURE: &str = include_str!("fixtures/collection.json");
+
+#[test]
+fn deserialize_collection_fixture() -> modrinth_api::Result<()> {
+    let collection: Collection = serde_json::from_str(COLLECTION_FIXTURE)?;
+
+    assert_eq!(collection.id, "9Wb6z3vH");
+    assert_eq!(collection.user, "DzLrfrbK");
+    assert_eq!(collection.status, CollectionStatus::Listed);
+    assert_eq!(
+        collection.color.map(|color| color.to_string()).as_deref(),
+        Some("#1bd96a")
+    );
+    assert_eq!(collection.projects.len(), 3);
+    assert_eq!(collection.projects[0], "AANobbMI");
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_collections_from_mock() -> modrinth_api::Result<()> {
+    let (server, api) = common::mock_api().await;
+    Mock::given(method("GET"))
+        .and(path("/v3/collection/9Wb6z3vH"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(COLLECTION_FIXTURE))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v3/collections"))
+        .and(query_param("ids", r#"["9Wb6z3vH"]"#))
+        .respond_with(ResponseTemplate::new(200).set_body_string(format!("[{COLLECTION_FIXTURE}]")))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v3/user/jellysquid3/collections"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("[]"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let id = "9Wb6z3vH".parse()?;
+    let collection = api.v3().get_collection(&id).await?;
+    assert_eq!(collection.name, "Performance");
+    assert_eq!(api.v3().get_collections(&[id]).await?, [collection]);
+    assert!(
+        api.v3()
+            .get_user_collections("jellysquid3")
+            .await?
+            .is_empty()
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn collection_bodies_from_mock() -> modrinth_api::Result<()> {
+    let (server, api) = common::mock_api().await;
+    Mock::given(method("POST"))
+        .and(path("/v3/collection"))
+        .and(body_json(serde_json::json!({
+            "name": "Performance",
+            "projects": ["AANobbMI"],
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_string(COLLECTION_FIXTURE))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path("/v3/collection/9Wb6z3vH"))
+        .and(body_json(serde_json::json!({
+            "status": "private",
+            "new_projects": ["AANobbMI", "gvQqBUqZ"],
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/v3/collection/9Wb6z3vH"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let collection = api
+        .v3()
+        .create_collection(&CreateCollection {
+            name: "Performance".into(),
+            description: None,
+            projects: vec!["AANobbMI".parse()?],
+        })
+        .await?;
+    api.v3()
+        .modify_collection(
+            &collection.id,
+            &EditCollection {
+                status: Some(CollectionStatus::Private),
+                projects: Some(vec!["AANobbMI".parse()?, "gvQqBUqZ".parse()?]),
+                ..Default::default()
+            },
+        )
+        .await?;
+    api.v3().delete_collection(&collection.id).await
+}
@@ -0,0 +1,13 @@
+//! Helpers shared by the integration tests
+
+use modrinth_api::ModrinthAPI;
+use wiremock::MockServer;
+
+/// Starts a mock server and returns it with a client that sends all requests to it
+pub async fn mock_api() -> (MockServer, ModrinthAPI) {
+    let server = MockServer::start().await;
+    let api = ModrinthAPI::default()
+        .with_base_url(server.uri().parse().unwrap())
+        .unwrap();
+    (server, api)
+}
@@ -0,0 +1,178 @@
+use modrinth_api::ModrinthAPI;
+use modrinth_api::structs::game_versions::{
+    GameVersion, GameVersionKind, GameVersionRange, GameVersionTag, ReleaseStage,
+    sort_by_release_date,
+};
+
+#[test]
+fn parse_game_versions() {
+    assert_eq!(
+        GameVersion::from("1.20.1").kind(),
+        GameVersionKind::Release {
+            major: 1,
+            minor: 20,
+            patch: 1,
+            stage: ReleaseStage::Release,
+        }
+    );
+    assert_eq!(
+        GameVersion::from("1.20-pre1").kind(),
+        GameVersionKind::Release {
+            major: 1,
+            minor: 20,
+            patch: 0,
+            stage: ReleaseStage::PreRelease(1),
+        }
+    );
+    assert_eq!(
+        GameVersion::from("1.14 Pre-Release 2").kind(),
+        GameVersion::from("1.14-pre2").kind()
+    );
+    assert_eq!(
+        GameVersion::from("1.20.1-rc1").kind(),
+        GameVersionKind::Release {
+            major: 1,
+            minor: 20,
+            patch: 1,
+            stage: ReleaseStage::ReleaseCandidate(1),
+        }
+    );
+    assert_eq!(
+        GameVersion::from("23w45a").kind(),
+        GameVersionKind::Snapshot {
+            year: 23,
+            week: 45,
+            build: 'a',
+        }
+    );
+    for other in ["b1.7.3", "3D Shareware v1.34", "20w14infinite"] {
+        assert_eq!(GameVersion::from(other).kind(), GameVersionKind::Other);
+        assert_eq!(GameVersion::from(other).to_string(), other);
+    }
+}
+
+#[test]
+fn order_game_versions() {
+    let mut versions: Vec<GameVersion> = [
+        "1.20",
+        "1.9",
+        "1.20-rc1",
+        "23w45a",
+        "1.20.1",
+        "1.20-pre2",
+        "1.20-pre10",
+        "23w07b",
+        "23w07a",
+        "b1.7.3",
+    ]
+    .into_iter()
+    .map(GameVersion::from)
+    .collect();
+    versions.sort();
+
+    assert_eq!(
+        versions,
+        [
+            "b1.7.3",
+            "23w07a",
+            "23w07b",
+            "23w45a",
+            "1.9",
+            "1.20-pre2",
+            "1.20-pre10",
+            "1.20-rc1",
+            "1.20",
+            "1.20.1",
+        ]
+    );
+
+    // Equivalent versions are still ordered consistently with `Eq`
+    let (short, long) = (GameVersion::from("1.21"), GameVersion::from("1.21.0"));
+    assert_ne!(short, long);
+    assert!(short < long);
+    assert!(short.cmp_version(&long).is_eq());
+
+    // Without dates, snapshots sort below every release
+    assert!(GameVersion::from("23w45a").cmp(&"1.0".into()).is_lt());
+}
+
+#[test]
+fn sort_snapshots_by_release_date() -> modrinth_api::Result<()> {
+    let tags: Vec<GameVersionTag> = serde_json::from_str(
+        r#"[
+            { "version": "1.20.3", "version_type": "release", "date": "2023-12-05T10:00:00Z", "major": false },
+            { "version": "23w45a", "version_type": "snapshot", "date": "2023-11-08T13:00:00Z", "major": false },
+            { "version": "1.20.2", "version_type": "release", "date": "2023-09-21T10:00:00Z", "major": false }
+        ]"#,
+    )?;
+    let mut versions: Vec<GameVersion> = ["1.20.3", "23w45a", "1.20.2"]
+        .into_iter()
+        .map(GameVersion::from)
+        .collect();
+    sort_by_release_date(&mut versions, &tags);
+
+    assert_eq!(versions, ["1.20.2", "23w45a", "1.20.3"]);
+
+    // Versions without a date go last, the comparison stays a total order
+    let mut versions: Vec<GameVersion> = ["1.20.3", "1.0", "23w45a", "b1.7.3", "1.20.2"]
+        .into_iter()
+        .map(GameVersion::from)
+        .collect();
+    sort_by_release_date(&mut versions, &tags);
+
+    assert_eq!(versions, ["1.20.2", "23w45a", "1.20.3", "b1.7.3", "1.0"]);
+    Ok(())
+}
+
+#[test]
+fn match_game_version_ranges() -> modrinth_api::Result<()> {
+    let range: GameVersionRange = ">=1.20, <1.21".parse()?;
+    assert!(range.matches(&"1.20".into()));
+    assert!(range.matches(&"1.20.6".into()));
+    assert!(!range.matches(&"1.20-pre1".into()));
+    assert!(!range.matches(&"1.21".into()));
+    assert!(!range.matches(&"1.9".into()));
+    assert!(!range.matches(&"23w45a".into()));
+
+    let range: GameVersionRange = "1.20.1".parse()?;
+    assert!(range.matches(&"1.20.1".into()));
+    assert!(!range.matches(&"1.20.2".into()));
+
+    // Equivalent spellings of a release match each other
+    let range: GameVersionRange = "=1.21".parse()?;
+    assert!(range.matches(&"1.21.0".into()));
+    assert!(
+        !"!=1.21.0"
+            .parse::<GameVersionRange>()?
+            .matches(&"1.21".into())
+    );
+    assert!(
+        !"=b1.7.3"
+            .parse::<GameVersionRange>()?
+            .matches(&"b1.7.2".into())
+    );
+
+    assert!(">=".parse::<GameVersionRange>().is_err());
+    assert!("=>1.20".parse::<GameVersionRange>().is_err());
+    Ok(())
+}
+
+#[test]
+fn game_versions_round_trip_through_serde() -> modrinth_api::Result<()> {
+    let versions: Vec<GameVersion> = serde_json::from_str(r#"["1.20.1", "23w45a", "b1.7.3"]"#)?;
+    assert!(versions[0].is_release());
+    assert!(versions[1].is_snapshot());
+    assert_eq!(
+        serde_json::to_string(&versions)?,
+        r#"["1.20.1","23w45a","b1.7.3"]"#
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_game_versions() -> modrinth_api::Result<()> {
+    let api = ModrinthAPI::default();
+    let tags = api.get_game_versions().await?;
+    assert!(tags.iter().any(|tag| tag.version == "1.20.1"));
+    Ok(())
+}
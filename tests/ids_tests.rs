@@ -0,0 +1,44 @@
+use modrinth_api::structs::ids::{ProjectId, ProjectRef, VersionId};
+
+#[test]
+fn parse_and_decode_ids() -> modrinth_api::Result<()> {
+    assert_eq!("00000001".parse::<ProjectId>()?.to_u64(), 1);
+    assert_eq!("0000000z".parse::<ProjectId>()?.to_u64(), 61);
+    assert_eq!("00000010".parse::<VersionId>()?.to_u64(), 62);
+    assert_eq!("AANobbMI".parse::<ProjectId>()?, "AANobbMI");
+
+    assert!("AANobbM".parse::<ProjectId>().is_err());
+    assert!("AANobbMI1".parse::<ProjectId>().is_err());
+    assert!("sodium!!".parse::<ProjectId>().is_err());
+    Ok(())
+}
+
+#[test]
+fn ids_are_validated_by_serde() -> modrinth_api::Result<()> {
+    let id: VersionId = serde_json::from_str(r#""OihdIimA""#)?;
+    assert_eq!(serde_json::to_string(&id)?, r#""OihdIimA""#);
+
+    assert!(serde_json::from_str::<VersionId>(r#""sodium""#).is_err());
+    Ok(())
+}
+
+#[test]
+fn project_ref_from_str() {
+    assert_eq!(
+        ProjectRef::from("AANobbMI"),
+        ProjectRef::IdOrSlug("AANobbMI".into())
+    );
+    assert_eq!(
+        ProjectRef::from("sodium"),
+        ProjectRef::IdOrSlug("sodium".into())
+    );
+    // Only typed IDs are trusted
+    assert_eq!(
+        ProjectRef::from("AANobbMI".parse::<ProjectId>().unwrap()),
+        ProjectRef::Id("AANobbMI".parse().unwrap())
+    );
+    assert_eq!(
+        ProjectRef::from("xaeros-minimap").as_str(),
+        "xaeros-minimap"
+    );
+}
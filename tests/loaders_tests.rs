@@ -0,0 +1,46 @@
+use modrinth_api::structs::loaders::Loader;
+use modrinth_api::structs::search::Facet;
+
+#[test]
+fn loaders_round_trip_through_serde() -> modrinth_api::Result<()> {
+    let loaders: Vec<Loader> = serde_json::from_str(
+        r#"["fabric", "neoforge", "legacy-fabric", "bungeecord", "optifine", "cauldron"]"#,
+    )?;
+    assert_eq!(
+        loaders,
+        [
+            Loader::Fabric,
+            Loader::NeoForge,
+            Loader::LegacyFabric,
+            Loader::BungeeCord,
+            Loader::OptiFine,
+            Loader::Other("cauldron".into()),
+        ]
+    );
+    for loader in &loaders {
+        assert_eq!(serde_json::to_string(loader)?, format!(r#""{loader}""#));
+        assert_eq!(&loader.to_string().parse::<Loader>().unwrap(), loader);
+    }
+    assert_eq!("NeoForge".parse::<Loader>().unwrap(), Loader::NeoForge);
+    Ok(())
+}
+
+#[test]
+fn group_loaders() {
+    assert!(Loader::Quilt.is_mod_loader());
+    assert!(!Loader::Quilt.is_plugin_loader());
+    assert!(Loader::Paper.is_plugin_loader());
+    assert!(Loader::Velocity.is_plugin_loader() && Loader::Velocity.is_proxy());
+    assert!(!Loader::Paper.is_proxy());
+    assert!(Loader::Iris.is_shader_loader());
+    assert!(!Loader::Datapack.is_mod_loader() && !Loader::Datapack.is_plugin_loader());
+    assert!(!Loader::Other("cauldron".into()).is_mod_loader());
+}
+
+#[test]
+fn loaders_are_searched_as_categories() {
+    assert_eq!(
+        Facet::from(Loader::NeoForge),
+        Facet::Categories("neoforge".into())
+    );
+}
@@ -0,0 +1,71 @@
+mod common;
+
+use modrinth_api::structs::notifications::{Notification, NotificationBody};
+use modrinth_api::structs::projects::ProjectStatus;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+fn notification_with_body(body: &str) -> String {
+    format!(
+        r#"{{
+            "id": "UJY5hv6a",
+            "user_id": "MpxzqsyW",
+            "type": "project_update",
+            "title": "**My Project** has been updated!",
+            "text": "The project, My Project, has released a new version: 1.0.0",
+            "link": "mod/AABBCCDD/version/EEFFGGHH",
+            "read": false,
+            "created": "2023-10-01T12:00:00Z",
+            "actions": [{{ "title": "Accept", "action_route": ["POST", "team/XXXX/join"] }}],
+            "body": {body}
+        }}"#
+    )
+}
+
+#[test]
+fn deserialize_known_body() -> modrinth_api::Result<()> {
+    let json = notification_with_body(
+        r#"{ "type": "status_change", "project_id": "AABBCCDD", "old_status": "processing", "new_status": "approved" }"#,
+    );
+    let notification: Notification = serde_json::from_str(&json)?;
+
+    assert_eq!(
+        notification.body,
+        Some(NotificationBody::StatusChange {
+            project_id: "AABBCCDD".parse()?,
+            old_status: ProjectStatus::Processing,
+            new_status: ProjectStatus::Approved,
+        })
+    );
+    assert_eq!(notification.actions[0].action_route.0, "POST");
+    Ok(())
+}
+
+#[test]
+fn deserialize_unknown_body() -> modrinth_api::Result<()> {
+    let json = notification_with_body(r#"{ "type": "payout_available", "amount": 10 }"#);
+    let notification: Notification = serde_json::from_str(&json)?;
+
+    assert_eq!(notification.body, Some(NotificationBody::Unknown));
+    Ok(())
+}
+
+#[tokio::test]
+async fn bulk_notification_requests_from_mock() -> modrinth_api::Result<()> {
+    let (server, api) = common::mock_api().await;
+    for verb in ["GET", "PATCH", "DELETE"] {
+        Mock::given(method(verb))
+            .and(path("/v2/notifications"))
+            .and(query_param("ids", r#"["a1b2c3d4","e5f6g7h8"]"#))
+            .respond_with(ResponseTemplate::new(200).set_body_string("[]"))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    let ids = ["a1b2c3d4".parse()?, "e5f6g7h8".parse()?];
+    assert!(api.get_notifications(&ids).await?.is_empty());
+    api.mark_notifications_read(&ids).await?;
+    api.delete_notifications(&ids).await?;
+    Ok(())
+}
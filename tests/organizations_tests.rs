@@ -0,0 +1,147 @@
+#![cfg(feature = "v3")]
+
+mod common;
+
+use modrinth_api::structs::ids::UserId;
+use modrinth_api::structs::v3::organizations::{
+    CreateOrganization, EditOrganization, Organization,
+};
+use wiremock::matchers::{body_bytes, body_json, header, method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+const ORGANIZATION_FIXTURE: &str = include_str!("fixtures/organization.json");
+
+#[test]
+fn deserialize_organization_fixture() -> modrinth_api::Result<()> {
+    let organization: Organization = serde_json::from_str(ORGANIZATION_FIXTURE)?;
+
+    assert_eq!(organization.id, "DRs5XLXq");
+    assert_eq!(organization.team_id, "BZoBsPo6");
+    assert_eq!(organization.members.len(), 1);
+    assert!(organization.members[0].is_owner);
+    assert_eq!(organization.members[0].user.username, "jellysquid3");
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_organizations_from_mock() -> modrinth_api::Result<()> {
+    let (server, api) = common::mock_api().await;
+    Mock::given(method("GET"))
+        .and(path("/v3/organization/caffeinemc"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(ORGANIZATION_FIXTURE))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v3/organizations"))
+        .and(query_param("ids", r#"["caffeinemc","DRs5XLXq"]"#))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string(format!("[{ORGANIZATION_FIXTURE}]")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let organization = api.v3().get_organization("caffeinemc").await?;
+    assert_eq!(organization.name, "CaffeineMC");
+    assert_eq!(
+        api.v3()
+            .get_organizations(&["caffeinemc", "DRs5XLXq"])
+            .await?,
+        [organization]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn organization_bodies_from_mock() -> modrinth_api::Result<()> {
+    let (server, api) = common::mock_api().await;
+    Mock::given(method("POST"))
+        .and(path("/v3/organization"))
+        .and(body_json(serde_json::json!({
+            "slug": "caffeinemc",
+            "name": "CaffeineMC",
+            "description": "Performance mods for Minecraft",
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_string(ORGANIZATION_FIXTURE))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path("/v3/organization/DRs5XLXq"))
+        .and(body_json(serde_json::json!({ "name": "Caffeine" })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/v3/organization/DRs5XLXq"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let organization = api
+        .v3()
+        .create_organization(&CreateOrganization {
+            slug: "caffeinemc".into(),
+            name: "CaffeineMC".into(),
+            description: "Performance mods for Minecraft".into(),
+        })
+        .await?;
+    api.v3()
+        .modify_organization(
+            &organization.id,
+            &EditOrganization {
+                name: Some("Caffeine".into()),
+                ..Default::default()
+            },
+        )
+        .await?;
+    api.v3().delete_organization(&organization.id).await
+}
+
+#[tokio::test]
+async fn organization_projects_from_mock() -> modrinth_api::Result<()> {
+    let (server, api) = common::mock_api().await;
+    Mock::given(method("POST"))
+        .and(path("/v3/organization/caffeinemc/projects"))
+        .and(body_json(serde_json::json!({ "project_id": "AANobbMI" })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/v3/organization/caffeinemc/projects/sodium"))
+        .and(body_json(serde_json::json!({ "new_owner": "TEZXhE2U" })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let new_owner: UserId = "TEZXhE2U".parse()?;
+    api.v3()
+        .add_organization_project("caffeinemc", "AANobbMI")
+        .await?;
+    api.v3()
+        .remove_organization_project("caffeinemc", "sodium", &new_owner)
+        .await
+}
+
+#[tokio::test]
+async fn change_organization_icon_from_mock() -> modrinth_api::Result<()> {
+    let (server, api) = common::mock_api().await;
+    Mock::given(method("PATCH"))
+        .and(path("/v3/organization/caffeinemc/icon"))
+        .and(query_param("ext", "jpg"))
+        .and(header("content-type", "image/jpeg"))
+        .and(body_bytes(b"\xff\xd8\xff".to_vec()))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    api.v3()
+        .change_organization_icon("caffeinemc", "jpg", b"\xff\xd8\xff".to_vec())
+        .await
+}
@@ -0,0 +1,134 @@
+mod common;
+
+use modrinth_api::ModrinthAPI;
+use modrinth_api::structs::projects::{
+    Color, MonetizationStatus, Project, ProjectStatus, ProjectSupportRange, ProjectType,
+};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, ResponseTemplate};
+
+const PROJECT_FIXTURE: &str = include_str!("fixtures/project.json");
+
+#[test]
+fn deserialize_project_fixture() -> modrinth_api::Result<()> {
+    let project: Project = serde_json::from_str(PROJECT_FIXTURE)?;
+
+    assert_eq!(project.id, "AANobbMI");
+    assert_eq!(project.team, "4reLOAKe");
+    assert_eq!(project.project_type, ProjectType::Mod);
+    assert_eq!(project.client_side, ProjectSupportRange::Required);
+    assert_eq!(project.status, ProjectStatus::Approved);
+    assert_eq!(project.monetization_status, MonetizationStatus::Monetized);
+    assert_eq!(project.body_url, None);
+    assert_eq!(project.moderator_message, None);
+    assert_eq!(project.wiki_url, None);
+    assert_eq!(
+        project.source_url.as_ref().map(|url| url.host_str()),
+        Some(Some("github.com"))
+    );
+    assert_eq!(
+        project.donation_links()[0].url.as_str(),
+        "https://www.patreon.com/jellysquid"
+    );
+    assert_eq!(
+        project.organization.as_ref().map(|id| id.as_str()),
+        Some("DRs5XLXq")
+    );
+    assert_eq!(project.thread_id, "Mtu7XwEP");
+    assert_eq!(project.requested_status, None);
+    assert_eq!(project.gallery.len(), 2);
+    assert!(project.gallery[0].featured);
+    assert!(
+        project.gallery[0]
+            .raw_url
+            .as_ref()
+            .is_some_and(|url| url.path().ends_with(".png"))
+    );
+    assert_eq!(project.versions.len(), 10);
+    assert_eq!(
+        project.color.map(|color| color.to_string()).as_deref(),
+        Some("#84cc6c")
+    );
+    Ok(())
+}
+
+#[test]
+fn color_components_and_hex() -> modrinth_api::Result<()> {
+    let color = Color::from_rgb(0x1b, 0xd9, 0x6a);
+    assert_eq!((color.r(), color.g(), color.b()), (0x1b, 0xd9, 0x6a));
+    assert_eq!(color.to_string(), "#1bd96a");
+    assert_eq!(u32::from(color), 0x1bd96a);
+
+    assert_eq!(serde_json::to_string(&color)?, "1825130");
+    assert_eq!(serde_json::from_str::<Color>("1825130")?, color);
+    assert_eq!(Color::try_from(0x000001)?.to_string(), "#000001");
+
+    assert!(matches!(
+        Color::try_from(0x1000000),
+        Err(modrinth_api::Error::InvalidColor(0x1000000))
+    ));
+    assert!(serde_json::from_str::<Color>("16777216").is_err());
+    Ok(())
+}
+
+#[test]
+fn project_round_trips_through_serde() -> modrinth_api::Result<()> {
+    let project: Project = serde_json::from_str(PROJECT_FIXTURE)?;
+    let json = serde_json::to_string(&project)?;
+    let reparsed: Project = serde_json::from_str(&json)?;
+
+    assert_eq!(
+        serde_json::to_value(&reparsed)?,
+        serde_json::to_value(&project)?
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn follow_project_from_mock() -> modrinth_api::Result<()> {
+    let (server, api) = common::mock_api().await;
+    for verb in ["POST", "DELETE"] {
+        Mock::given(method(verb))
+            .and(path("/v2/project/sodium/follow"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    api.follow_project("sodium").await?;
+    api.unfollow_project("sodium").await
+}
+
+#[tokio::test]
+async fn resolve_project_slug() -> modrinth_api::Result<()> {
+    let api = ModrinthAPI::default();
+    let id = api.resolve_project_id("sodium").await?;
+    assert_eq!(id, "AANobbMI");
+
+    // The second lookup is served from the cache, including through clones
+    assert_eq!(api.clone().resolve_project_id("Sodium").await?, id);
+    Ok(())
+}
+
+#[tokio::test]
+async fn resolve_id_shaped_slug_from_mock() -> modrinth_api::Result<()> {
+    let (server, api) = common::mock_api().await;
+    // `debugify` is a valid ID as well as a slug, so it must be checked with the API
+    Mock::given(method("GET"))
+        .and(path("/v2/project/debugify/check"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(r#"{ "id": "QwxR6Gcd" }"#))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let id = api.resolve_project_id("debugify").await?;
+    assert_eq!(id, "QwxR6Gcd");
+    // Served from the cache, typed IDs are returned without a request
+    assert_eq!(api.clone().resolve_project_id("debugify").await?, id);
+    assert_eq!(api.resolve_project_id(&id).await?, id);
+
+    api.forget_project_id("debugify");
+    assert_eq!(api.resolve_project_id("debugify").await?, id);
+    Ok(())
+}
@@ -0,0 +1,51 @@
+mod common;
+
+use modrinth_api::structs::reports::ReportType;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+#[tokio::test]
+async fn get_reports_from_mock() -> modrinth_api::Result<()> {
+    let (server, api) = common::mock_api().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/reports"))
+        .and(query_param("ids", r#"["a1b2c3d4","e5f6g7h8"]"#))
+        .respond_with(ResponseTemplate::new(200).set_body_string("[]"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let ids = ["a1b2c3d4".parse()?, "e5f6g7h8".parse()?];
+    let reports = api.get_reports(&ids).await?;
+    assert!(reports.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_report_types_from_mock() -> modrinth_api::Result<()> {
+    let (server, api) = common::mock_api().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/tag/report_type"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(r#"["spam","name-squatting","other","impersonation"]"#),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    assert_eq!(
+        api.get_report_types().await?,
+        [
+            ReportType::Spam,
+            ReportType::NameSquatting,
+            ReportType::Other("other".into()),
+            ReportType::Other("impersonation".into()),
+        ]
+    );
+    assert_eq!(
+        serde_json::to_string(&ReportType::Other("impersonation".into()))?,
+        r#""impersonation""#
+    );
+    Ok(())
+}
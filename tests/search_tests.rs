@@ -1,6 +1,16 @@
+mod common;
+
+use futures_util::TryStreamExt;
 use modrinth_api::ModrinthAPI;
-use modrinth_api::structs::projects::ProjectType;
-use modrinth_api::structs::search::{ExtendedSearch, Facet, Sort};
+use modrinth_api::structs::projects::{
+    MonetizationStatus, ProjectStatus, ProjectSupportRange, ProjectType,
+};
+use modrinth_api::structs::search::{
+    ExtendedSearch, Facet, Op, SearchQuery, Sort, StructuredFacet,
+};
+use std::collections::HashSet;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
 
 #[tokio::test]
 async fn get_valid_project() -> modrinth_api::Result<()> {
@@ -23,14 +33,10 @@ async fn asrt_slug_error() -> modrinth_api::Result<()> {
 async fn search_project() -> modrinth_api::Result<()> {
     let api = ModrinthAPI::default();
     let response = api
-        .search(
-            "xaeros",
-            &Sort::Downloads,
-            None,
-            Some(ExtendedSearch {
-                offset: None,
-                facets: vec![vec![Facet::ProjectType(ProjectType::Mod)]],
-            }),
+        .search_with(
+            &SearchQuery::new("xaeros")
+                .sort(Sort::Downloads)
+                .facet_and(Facet::ProjectType(ProjectType::Mod)),
         )
         .await?;
 
@@ -46,14 +52,10 @@ async fn search_project() -> modrinth_api::Result<()> {
 async fn test_fetching_project_with_mut() -> modrinth_api::Result<()> {
     let api = ModrinthAPI::default();
     let response = api
-        .search(
-            "xaeros",
-            &Sort::Downloads,
-            None,
-            Some(ExtendedSearch {
-                offset: None,
-                facets: vec![vec![Facet::ProjectType(ProjectType::Mod)]],
-            }),
+        .search_with(
+            &SearchQuery::new("xaeros")
+                .sort(Sort::Downloads)
+                .facet_and(Facet::ProjectType(ProjectType::Mod)),
         )
         .await?;
 
@@ -73,14 +75,10 @@ async fn test_fetching_project_with_mut() -> modrinth_api::Result<()> {
 async fn test_fetching_project_without_mut() -> modrinth_api::Result<()> {
     let api = ModrinthAPI::default();
     let response = api
-        .search(
-            "xaeros",
-            &Sort::Downloads,
-            None,
-            Some(ExtendedSearch {
-                offset: None,
-                facets: vec![vec![Facet::ProjectType(ProjectType::Mod)]],
-            }),
+        .search_with(
+            &SearchQuery::new("xaeros")
+                .sort(Sort::Downloads)
+                .facet_and(Facet::ProjectType(ProjectType::Mod)),
         )
         .await?;
 
@@ -95,3 +93,419 @@ async fn test_fetching_project_without_mut() -> modrinth_api::Result<()> {
     assert_eq!(hit.slug, String::from("xaeros-minimap"));
     Ok(())
 }
+
+#[tokio::test]
+async fn search_stream_walks_pages() -> modrinth_api::Result<()> {
+    let api = ModrinthAPI::default();
+    let hits: Vec<_> = api
+        .search_stream("fabric", &Sort::Downloads, vec![], Some(10), Some(25))
+        .try_collect()
+        .await?;
+
+    assert_eq!(hits.len(), 25);
+    let ids: HashSet<_> = hits.iter().map(|hit| &hit.project_id).collect();
+    assert_eq!(ids.len(), 25);
+    Ok(())
+}
+
+#[tokio::test]
+async fn search_stream_rejects_page_size() {
+    let api = ModrinthAPI::default();
+    let result: modrinth_api::Result<Vec<_>> = api
+        .search_stream("fabric", &Sort::Downloads, vec![], Some(101), None)
+        .try_collect()
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(modrinth_api::Error::InvalidSearchLimit(101))
+    ));
+}
+
+#[tokio::test]
+#[allow(deprecated)]
+async fn search_rejects_limit() {
+    let api = ModrinthAPI::default();
+    let result = api
+        .search("fabric", &Sort::Downloads, Some(101), None)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(modrinth_api::Error::InvalidSearchLimit(101))
+    ));
+}
+
+#[test]
+fn search_query_url() -> modrinth_api::Result<()> {
+    let url = SearchQuery::new("sodium")
+        .sort(Sort::Downloads)
+        .limit(50)
+        .offset(100)
+        .facet_or([])
+        .facet_and(Facet::ProjectType(ProjectType::Mod))
+        .url()?;
+
+    let query: Vec<_> = url.query_pairs().collect();
+    assert_eq!(url.path(), "/v2/search");
+    assert!(query.contains(&("index".into(), "downloads".into())));
+    assert!(query.contains(&("limit".into(), "50".into())));
+    assert!(query.contains(&("offset".into(), "100".into())));
+    assert_eq!(
+        query.iter().find(|(name, _)| name == "facets").unwrap().1,
+        format!(r#"[["{}"]]"#, Facet::ProjectType(ProjectType::Mod))
+    );
+
+    assert!(matches!(
+        SearchQuery::new("sodium").limit(0).url(),
+        Err(modrinth_api::Error::InvalidSearchLimit(0))
+    ));
+    Ok(())
+}
+
+#[test]
+fn facet_round_trips_through_display() -> modrinth_api::Result<()> {
+    let facets = [
+        Facet::ProjectType(ProjectType::ResourcePack),
+        Facet::Categories("fabric".into()),
+        Facet::Versions("1.20.1".into()),
+        Facet::OpenSource(true),
+        Facet::License("MIT".into()),
+        Facet::Title("Sodium".into()),
+        Facet::Author("jellysquid3".into()),
+        Facet::ProjectID("AANobbMI".into()),
+        Facet::Downloads(Op::Ge, 1000),
+        Facet::Follows(Op::Lt, 50),
+        Facet::DateCreated(Op::Gt, "2023-11-14T22:13:20Z".parse().unwrap()),
+        Facet::DateModified(Op::Le, "2024-01-01T00:00:00Z".parse().unwrap()),
+        Facet::ClientSide(Op::Eq, ProjectSupportRange::Required),
+        Facet::ServerSide(Op::Ne, ProjectSupportRange::Unsupported),
+        Facet::custom("color", ">=", "1000")?,
+        Facet::custom("open_source", ":", "maybe")?,
+    ];
+    for facet in facets {
+        assert_eq!(facet.to_string().parse::<Facet>()?, facet);
+    }
+    Ok(())
+}
+
+#[test]
+fn parse_facet_expression() -> modrinth_api::Result<()> {
+    let Facet::Custom(custom) = "versions!=1.20.1".parse::<Facet>()? else {
+        panic!("`versions!=` has no dedicated variant");
+    };
+    assert_eq!(
+        (custom.facet_type(), custom.operation(), custom.value()),
+        ("versions", "!=", "1.20.1")
+    );
+    assert_eq!(
+        Facet::custom("downloads", ">", "5")?,
+        Facet::Downloads(Op::Gt, 5)
+    );
+    assert!(matches!(
+        "open_source:maybe".parse::<Facet>()?,
+        Facet::Custom(_)
+    ));
+    assert_eq!(
+        Facet::parse_expression("(categories:fabric OR categories:quilt) AND downloads>=1000")?,
+        vec![
+            vec![
+                Facet::Categories("fabric".into()),
+                Facet::Categories("quilt".into()),
+            ],
+            vec!["downloads>=1000".parse()?],
+        ]
+    );
+    assert_eq!(
+        Facet::parse_expression("(categories:fabric or categories:quilt) and versions:1.20.1")?,
+        Facet::parse_expression("(categories:fabric OR categories:quilt) AND (versions:1.20.1)")?,
+    );
+    assert_eq!(
+        Facet::parse_expression("categories:fabric and versions:1.20.1")?,
+        vec![
+            vec![Facet::Categories("fabric".into())],
+            vec![Facet::Versions("1.20.1".into())],
+        ]
+    );
+    assert_eq!(
+        Facet::parse_expression("categories:fabric Or categories:quilt")?,
+        vec![vec![
+            Facet::Categories("fabric".into()),
+            Facet::Categories("quilt".into()),
+        ]]
+    );
+
+    // Mixing AND and OR without parentheses has no implied precedence
+    assert!(
+        Facet::parse_expression("categories:fabric OR categories:quilt AND versions:1.20.1")
+            .is_err()
+    );
+    assert!(
+        Facet::parse_expression("versions:1.20.1 and categories:fabric or categories:quilt")
+            .is_err()
+    );
+
+    assert!("categories".parse::<Facet>().is_err());
+    assert!(Facet::parse_expression("(categories:fabric AND versions:1.20.1)").is_err());
+    assert!(Facet::parse_expression("(categories:fabric").is_err());
+    Ok(())
+}
+
+#[test]
+fn facets_use_wire_format() -> modrinth_api::Result<()> {
+    assert_eq!(
+        Facet::ProjectType(ProjectType::ResourcePack).to_string(),
+        "project_type:resourcepack"
+    );
+    assert_eq!(
+        Facet::Categories("fabric".into()).to_string(),
+        "categories:fabric"
+    );
+    assert_eq!(
+        Facet::Downloads(Op::Ge, 1000).to_string(),
+        "downloads>=1000"
+    );
+    assert_eq!(
+        Facet::DateCreated(Op::Gt, "2023-11-14T22:13:20Z".parse().unwrap()).to_string(),
+        "created_timestamp>1700000000"
+    );
+    assert_eq!(
+        "date_created>=2023-11-14T22:13:20Z".parse::<Facet>()?,
+        "created_timestamp>=1700000000".parse::<Facet>()?
+    );
+    assert_eq!(
+        serde_json::to_string(&[[Facet::ServerSide(Op::Ne, ProjectSupportRange::Unsupported)]])?,
+        r#"[["server_side!=unsupported"]]"#
+    );
+    Ok(())
+}
+
+#[test]
+fn saved_search_round_trips_through_serde() -> modrinth_api::Result<()> {
+    let search = ExtendedSearch {
+        offset: Some(40),
+        facets: vec![
+            vec![
+                Facet::Categories("fabric".into()),
+                Facet::Categories("quilt".into()),
+            ],
+            vec![Facet::Downloads(Op::Ge, 1000)],
+            vec![Facet::custom("color", "!=", "0")?],
+            // Custom facets on known metadata, with values that don't fit the dedicated variant
+            vec![
+                Facet::custom("client_side", "=", "maybe")?,
+                Facet::custom("downloads", ">", "many")?,
+            ],
+        ],
+    };
+    let json = serde_json::to_string(&search)?;
+    assert_eq!(
+        json,
+        r#"{"offset":40,"facets":[["categories:fabric","categories:quilt"],["downloads>=1000"],["color!=0"],["client_side=maybe","downloads>many"]]}"#
+    );
+    assert_eq!(serde_json::from_str::<ExtendedSearch>(&json)?, search);
+
+    for sort in [
+        Sort::Relevance,
+        Sort::Downloads,
+        Sort::Follows,
+        Sort::Newest,
+        Sort::Updated,
+    ] {
+        let json = serde_json::to_string(&sort)?;
+        assert_eq!(json, format!(r#""{sort}""#));
+        assert_eq!(serde_json::from_str::<Sort>(&json)?, sort);
+    }
+    Ok(())
+}
+
+#[test]
+fn structured_facets_match_wire_facets() -> modrinth_api::Result<()> {
+    let pairs = [
+        (
+            r#"{ "type": "downloads", "operation": ">=", "value": 1000 }"#,
+            r#""downloads>=1000""#,
+        ),
+        (
+            r#"{ "type": "open_source", "operation": ":", "value": true }"#,
+            r#""open_source:true""#,
+        ),
+        (
+            r#"{ "type": "categories", "operation": "=", "value": "fabric" }"#,
+            r#""categories:fabric""#,
+        ),
+    ];
+    for (structured, wire) in pairs {
+        assert_eq!(
+            serde_json::from_str::<Facet>(structured)?,
+            serde_json::from_str::<Facet>(wire)?
+        );
+    }
+    assert!(serde_json::from_str::<Facet>(r#""downloads>=""#).is_err());
+    Ok(())
+}
+
+#[test]
+fn structured_facets_round_trip() -> modrinth_api::Result<()> {
+    let structured = serde_json::json!([
+        { "type": "downloads", "operation": ">=", "value": 1000 },
+        { "type": "open_source", "operation": ":", "value": true },
+        { "type": "categories", "operation": ":", "value": "fabric" },
+        { "type": "project_type", "operation": ":", "value": "mod" },
+        { "type": "created_timestamp", "operation": "<", "value": 1700000000 },
+        { "type": "client_side", "operation": "!=", "value": "unsupported" },
+        { "type": "color", "operation": "=", "value": "red" },
+        { "type": "open_source", "operation": ":", "value": "maybe" },
+    ]);
+    let facets: Vec<Facet> = serde_json::from_value(structured.clone())?;
+    assert_eq!(facets[0], Facet::Downloads(Op::Ge, 1000));
+
+    let facets: Vec<StructuredFacet> = facets.into_iter().map(StructuredFacet::from).collect();
+    assert_eq!(serde_json::to_value(&facets)?, structured);
+    Ok(())
+}
+
+#[test]
+fn deserialize_unknown_project_enums() -> modrinth_api::Result<()> {
+    assert_eq!(
+        serde_json::from_str::<ProjectType>(r#""minigame""#)?,
+        ProjectType::Other("minigame".into())
+    );
+    assert_eq!(
+        serde_json::from_str::<ProjectType>(r#""resourcepack""#)?,
+        ProjectType::ResourcePack
+    );
+    assert_eq!(
+        serde_json::from_str::<ProjectStatus>(r#""banned""#)?,
+        ProjectStatus::Other("banned".into())
+    );
+    assert_eq!(
+        serde_json::from_str::<MonetizationStatus>(r#""paused""#)?,
+        MonetizationStatus::Other("paused".into())
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn search_with_from_mock() -> modrinth_api::Result<()> {
+    let (server, api) = common::mock_api().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/search"))
+        .and(query_param("query", "sodium"))
+        .and(query_param("facets", r#"[["categories:fabric"]]"#))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(r#"{ "hits": [], "offset": 0, "limit": 20, "total_hits": 0 }"#),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let query = SearchQuery::new("sodium").facet_and(Facet::Categories("fabric".into()));
+    assert!(api.search_with(&query).await?.hits.is_empty());
+    Ok(())
+}
+
+fn search_page(ids: &[&str], offset: usize, total_hits: usize) -> String {
+    let hits: Vec<_> = ids
+        .iter()
+        .map(|id| {
+            serde_json::json!({
+                "slug": id.to_lowercase(),
+                "title": id,
+                "description": "",
+                "categories": ["fabric"],
+                "client_side": "required",
+                "server_side": "optional",
+                "project_type": "mod",
+                "downloads": 0,
+                "icon_url": null,
+                "color": null,
+                "thread_id": null,
+                "monetization_status": "monetized",
+                "project_id": id,
+                "author": "jellysquid3",
+                "display_categories": ["fabric"],
+                "versions": ["1.21"],
+                "game_versions": ["1.21"],
+                "follows": 0,
+                "date_created": "2024-01-01T00:00:00Z",
+                "date_modified": "2024-01-01T00:00:00Z",
+                "latest_version": "1.21",
+                "license": "MIT",
+                "gallery": [],
+                "featured_gallery": null
+            })
+        })
+        .collect();
+    serde_json::json!({
+        "hits": hits,
+        "offset": offset,
+        "limit": ids.len(),
+        "total_hits": total_hits,
+    })
+    .to_string()
+}
+
+#[tokio::test]
+async fn search_stream_pages_from_mock() -> modrinth_api::Result<()> {
+    let (server, api) = common::mock_api().await;
+    let ids = ["AAAAAAA1", "AAAAAAA2", "AAAAAAA3", "AAAAAAA4", "AAAAAAA5"];
+    for offset in [0, 2, 4] {
+        let page = &ids[offset..ids.len().min(offset + 2)];
+        Mock::given(method("GET"))
+            .and(path("/v2/search"))
+            .and(query_param("offset", offset.to_string()))
+            .and(query_param("limit", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(search_page(
+                page,
+                offset,
+                ids.len(),
+            )))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    let hits: Vec<_> = api
+        .search_stream("sodium", &Sort::Downloads, vec![], Some(2), None)
+        .try_collect()
+        .await?;
+    let found: Vec<_> = hits.iter().map(|hit| hit.project_id.as_str()).collect();
+    assert_eq!(found, ids);
+    Ok(())
+}
+
+#[tokio::test]
+async fn search_stream_stops_at_max_results_from_mock() -> modrinth_api::Result<()> {
+    let (server, api) = common::mock_api().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/search"))
+        .and(query_param("offset", "0"))
+        .and(query_param("limit", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(search_page(
+            &["AAAAAAA1", "AAAAAAA2"],
+            0,
+            5,
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+    // Only the one hit still wanted is requested from the second page
+    Mock::given(method("GET"))
+        .and(path("/v2/search"))
+        .and(query_param("offset", "2"))
+        .and(query_param("limit", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(search_page(&["AAAAAAA3"], 2, 5)))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let hits: Vec<_> = api
+        .search_stream("sodium", &Sort::Downloads, vec![], Some(2), Some(3))
+        .try_collect()
+        .await?;
+    let found: Vec<_> = hits.iter().map(|hit| hit.project_id.as_str()).collect();
+    assert_eq!(found, ["AAAAAAA1", "AAAAAAA2", "AAAAAAA3"]);
+    Ok(())
+}
@@ -0,0 +1,60 @@
+mod common;
+
+use modrinth_api::structs::ids::UserId;
+use modrinth_api::structs::teams::ProjectPermissions;
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, ResponseTemplate};
+
+#[test]
+fn permissions_round_trip_as_integer() -> modrinth_api::Result<()> {
+    let permissions = ProjectPermissions::UPLOAD_VERSION | ProjectPermissions::VIEW_PAYOUTS;
+    assert_eq!(serde_json::to_string(&permissions)?, "513");
+
+    // Bits unknown to this crate must not be dropped
+    let permissions: ProjectPermissions = serde_json::from_str("1029")?;
+    assert!(
+        permissions.contains(ProjectPermissions::UPLOAD_VERSION | ProjectPermissions::EDIT_DETAILS)
+    );
+    assert_eq!(serde_json::to_string(&permissions)?, "1029");
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_team_members_from_mock() -> modrinth_api::Result<()> {
+    let (server, api) = common::mock_api().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/team/4reLOAKe/members"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("[]"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let members = api.get_team_members(&"4reLOAKe".parse()?).await?;
+    assert!(members.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn team_member_requests_from_mock() -> modrinth_api::Result<()> {
+    let (server, api) = common::mock_api().await;
+    Mock::given(method("POST"))
+        .and(path("/v2/team/4reLOAKe/members"))
+        .and(body_json(serde_json::json!({ "user_id": "DzLrfrbK" })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/v2/team/4reLOAKe/members/DzLrfrbK"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let team_id = "4reLOAKe".parse()?;
+    let user_id: UserId = "DzLrfrbK".parse()?;
+    api.add_team_member(&team_id, &user_id).await?;
+    api.remove_team_member(&team_id, &user_id).await?;
+    api.remove_team_member(&team_id, "DzLrfrbK").await?;
+    Ok(())
+}
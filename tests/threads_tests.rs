@@ -0,0 +1,37 @@
+mod common;
+
+use modrinth_api::structs::threads::MessageBody;
+use wiremock::matchers::{body_json, method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+#[tokio::test]
+async fn get_threads_from_mock() -> modrinth_api::Result<()> {
+    let (server, api) = common::mock_api().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/threads"))
+        .and(query_param("ids", r#"["a1b2c3d4"]"#))
+        .respond_with(ResponseTemplate::new(200).set_body_string("[]"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    assert!(api.get_threads(&["a1b2c3d4".parse()?]).await?.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn send_thread_message_from_mock() -> modrinth_api::Result<()> {
+    let (server, api) = common::mock_api().await;
+    Mock::given(method("POST"))
+        .and(path("/v2/thread/a1b2c3d4"))
+        .and(body_json(serde_json::json!({
+            "body": { "type": "text", "body": "Fixed, thanks!", "private": false, "replying_to": null }
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    api.send_thread_message(&"a1b2c3d4".parse()?, &MessageBody::text("Fixed, thanks!"))
+        .await
+}
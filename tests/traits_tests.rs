@@ -0,0 +1,111 @@
+//! Compile-time checks that the public models derive a consistent set of traits
+//!
+//! Every model is `Debug + Clone + PartialEq`, models are `Eq` unless they contain
+//! floating point numbers, and enums and IDs are also `Hash` so they can be used as keys.
+
+use modrinth_api::structs::{
+    game_versions::*, ids::*, loaders::*, notifications::*, projects::*, reports::*, search::*,
+    teams::*, threads::*, users::*, versions::*,
+};
+use std::{fmt::Debug, hash::Hash};
+
+fn model<T: Debug + Clone + PartialEq>() {}
+fn eq_model<T: Debug + Clone + Eq>() {}
+fn key<T: Debug + Clone + Eq + Hash>() {}
+
+#[test]
+fn models_derive_common_traits() {
+    // Structs containing `f64`
+    model::<TeamMember>();
+    model::<EditTeamMember>();
+    model::<PayoutHistory>();
+    model::<Payout>();
+
+    eq_model::<Project>();
+    eq_model::<License>();
+    eq_model::<DonationLink>();
+    eq_model::<ModeratorMessage>();
+    eq_model::<GalleryItem>();
+    eq_model::<Version>();
+    eq_model::<ProjectVersionsFilter>();
+    eq_model::<Dependencies>();
+    eq_model::<File>();
+    eq_model::<modrinth_api::structs::versions::Hash>();
+    eq_model::<Response>();
+    eq_model::<SearchHit>();
+    eq_model::<ExtendedSearch>();
+    eq_model::<SearchQuery>();
+    eq_model::<Notification>();
+    eq_model::<Report>();
+    eq_model::<CreateReport>();
+    eq_model::<EditReport>();
+    eq_model::<Thread>();
+    eq_model::<ThreadMessage>();
+    eq_model::<User>();
+    eq_model::<EditUser>();
+    eq_model::<GameVersionRange>();
+    eq_model::<GameVersionTag>();
+
+    key::<ProjectId>();
+    key::<VersionId>();
+    key::<UserId>();
+    key::<TeamId>();
+    key::<ProjectRef>();
+    key::<Sha1>();
+    key::<Sha512>();
+    key::<GameVersion>();
+    key::<Color>();
+    key::<ProjectPermissions>();
+    key::<OrganizationPermissions>();
+
+    key::<ProjectType>();
+    key::<MonetizationStatus>();
+    key::<ProjectSupportRange>();
+    key::<ProjectStatus>();
+    key::<modrinth_api::structs::projects::RequestedStatus>();
+    key::<modrinth_api::structs::versions::RequestedStatus>();
+    key::<DependencyType>();
+    key::<VersionType>();
+    key::<Status>();
+    key::<FileType>();
+    key::<Sort>();
+    key::<Op>();
+    key::<Facet>();
+    key::<NotificationBody>();
+    key::<ReportType>();
+    key::<ReportItemType>();
+    key::<ThreadType>();
+    key::<MessageBody>();
+    key::<UserRole>();
+    key::<Loader>();
+    key::<GameVersionKind>();
+    key::<ReleaseStage>();
+}
+
+#[cfg(feature = "v3")]
+#[test]
+fn v3_models_derive_common_traits() {
+    use modrinth_api::structs::v3::{
+        analytics::*, collections::*, organizations::*, projects as v3_projects, teams as v3_teams,
+        versions as v3_versions,
+    };
+
+    model::<v3_teams::TeamMember>();
+    model::<Organization>();
+    model::<Revenue>();
+    model::<TimeSeries<Revenue>>();
+
+    eq_model::<v3_projects::Project>();
+    eq_model::<v3_projects::Link>();
+    eq_model::<v3_projects::GalleryItem>();
+    eq_model::<v3_versions::Version>();
+    eq_model::<CreateOrganization>();
+    eq_model::<EditOrganization>();
+    eq_model::<Collection>();
+    eq_model::<CreateCollection>();
+    eq_model::<EditCollection>();
+    eq_model::<TimeSeries<u64>>();
+    eq_model::<AnalyticsFilter>();
+
+    key::<CollectionStatus>();
+}
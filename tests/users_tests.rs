@@ -0,0 +1,43 @@
+mod common;
+
+use modrinth_api::structs::users::EditUser;
+use wiremock::matchers::{body_bytes, body_json, header, method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+#[tokio::test]
+async fn change_user_icon_from_mock() -> modrinth_api::Result<()> {
+    let (server, api) = common::mock_api().await;
+    Mock::given(method("PATCH"))
+        .and(path("/v2/user/DzLrfrbK/icon"))
+        .and(query_param("ext", "png"))
+        .and(header("content-type", "image/png"))
+        .and(body_bytes(b"\x89PNG".to_vec()))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    api.change_user_icon("DzLrfrbK", "png", b"\x89PNG".to_vec())
+        .await
+}
+
+#[tokio::test]
+async fn modify_user_clears_fields_from_mock() -> modrinth_api::Result<()> {
+    let (server, api) = common::mock_api().await;
+    Mock::given(method("PATCH"))
+        .and(path("/v2/user/DzLrfrbK"))
+        .and(body_json(
+            serde_json::json!({ "name": "Jelly", "bio": null }),
+        ))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let edit = EditUser {
+        name: Some(Some("Jelly".into())),
+        bio: Some(None),
+        ..Default::default()
+    };
+    api.modify_user("DzLrfrbK", &edit).await
+}
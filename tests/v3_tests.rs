@@ -0,0 +1,84 @@
+#![cfg(feature = "v3")]
+
+mod common;
+
+use modrinth_api::ModrinthAPI;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, ResponseTemplate};
+
+#[tokio::test]
+async fn get_v3_project() -> modrinth_api::Result<()> {
+    let api = ModrinthAPI::default();
+    // AANobbMI -> Sodium
+    let project = api.v3().get_project("AANobbMI").await?;
+
+    assert!(project.project_types.iter().any(|t| t == "mod"));
+    assert!(project.games.iter().any(|g| g == "minecraft-java"));
+    assert!(project.fields.contains_key("game_versions"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_organization_projects() -> modrinth_api::Result<()> {
+    let api = ModrinthAPI::default();
+    let organization = api.v3().get_organization("caffeinemc").await?;
+    let projects = api.v3().get_organization_projects(&organization.id).await?;
+
+    assert!(projects.iter().any(|p| p.id == "AANobbMI"));
+    Ok(())
+}
+
+#[test]
+fn deserialize_project_fields() -> modrinth_api::Result<()> {
+    use modrinth_api::structs::v3::projects::Project;
+
+    let project: Project = serde_json::from_str(include_str!("fixtures/v3_project.json"))?;
+    assert_eq!(project.name, "Sodium");
+    assert_eq!(
+        project.fields["game_versions"],
+        serde_json::json!(["1.21", "1.21.1"])
+    );
+    assert_eq!(
+        project.fields["side_types_migration_review_status"],
+        "reviewed"
+    );
+    assert!(project.fields["mrpack_loaders"].is_null());
+    assert!(!project.fields.contains_key("name"));
+    Ok(())
+}
+
+#[test]
+fn deserialize_time_series() -> modrinth_api::Result<()> {
+    use modrinth_api::structs::v3::analytics::{Analytics, Revenue};
+
+    let downloads: Analytics<u64> =
+        serde_json::from_str(r#"{ "AANobbMI": { "1700006400": 5, "1699920000": 3 } }"#)?;
+    let series: Vec<_> = downloads["AANobbMI"]
+        .iter()
+        .map(|(date, count)| (date.timestamp(), *count))
+        .collect();
+    assert_eq!(series, vec![(1699920000, 3), (1700006400, 5)]);
+
+    let revenue: Analytics<Revenue> =
+        serde_json::from_str(r#"{ "AANobbMI": { "1699920000": "1.25", "1700006400": 0.5 } }"#)?;
+    assert_eq!(
+        revenue["AANobbMI"].iter().map(|(_, r)| r.0).sum::<f64>(),
+        1.75
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn v3_requests_use_base_url() -> modrinth_api::Result<()> {
+    let (server, api) = common::mock_api().await;
+    Mock::given(method("GET"))
+        .and(path("/v3/organization/caffeinemc/projects"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("[]"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let projects = api.v3().get_organization_projects("caffeinemc").await?;
+    assert!(projects.is_empty());
+    Ok(())
+}
@@ -1,5 +1,15 @@
+mod common;
+
 use modrinth_api::ModrinthAPI;
-use modrinth_api::structs::versions::{ProjectVersionParams, ProjectVersionsFilter};
+use modrinth_api::structs::loaders::Loader;
+use modrinth_api::structs::versions::{
+    DependencyType, FileType, ProjectVersionsFilter, Sha1, Sha512, Status, Version, VersionType,
+};
+use modrinth_api::utils::hash_reader;
+use wiremock::matchers::{method, path, query_param, query_param_is_missing};
+use wiremock::{Mock, ResponseTemplate};
+
+const VERSION_FIXTURE: &str = include_str!("fixtures/version.json");
 
 #[tokio::test]
 async fn get_version_from_project() -> modrinth_api::Result<()> {
@@ -16,7 +26,7 @@ async fn get_version_from_project() -> modrinth_api::Result<()> {
 async fn get_version_from_project_extra() -> modrinth_api::Result<()> {
     let api = ModrinthAPI::default();
     let options = ProjectVersionsFilter {
-        loaders: Some(&["fabric"]),
+        loaders: Some(&[Loader::Fabric]),
         game_versions: Some(&["1.20.1"]),
         featured: Some(true),
     };
@@ -29,31 +39,200 @@ async fn get_version_from_project_extra() -> modrinth_api::Result<()> {
 }
 
 #[tokio::test]
-async fn get_single_version_from_project_extra() -> modrinth_api::Result<()> {
+async fn get_single_version_from_project() -> modrinth_api::Result<()> {
     let api = ModrinthAPI::default();
-    let options = ProjectVersionParams {
-        number: Some("mc1.20.1-0.5.13-fabric"),
-        loaders: Some(&["fabric"]),
-        game_versions: Some(&["1.20.1"]),
-        featured: Some(true),
-    };
-    let result = api.get_project_version("AANobbMI", Some(options)).await?;
+    let result = api
+        .get_project_version("AANobbMI", "mc1.20.1-0.5.13-fabric")
+        .await?;
 
-    assert!(result.name.contains("Sodium")); // assume that is Sodium...
+    assert!(result.unwrap().name.contains("Sodium")); // assume that is Sodium...
     Ok(())
 }
 
 #[tokio::test]
 async fn get_single_version_from_project_with_wrong_id() -> modrinth_api::Result<()> {
     let api = ModrinthAPI::default();
-    let options = ProjectVersionParams {
-        number: Some("2"),
-        loaders: Some(&["fabric"]),
+    let result = api.get_project_version("AANobbMI", "2").await?;
+
+    assert!(result.is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_project_version_from_mock() -> modrinth_api::Result<()> {
+    let (server, api) = common::mock_api().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/project/AANobbMI/version/mc1.20.1-0.5.13-fabric"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(VERSION_FIXTURE))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let version = api
+        .get_project_version("AANobbMI", "mc1.20.1-0.5.13-fabric")
+        .await?
+        .unwrap();
+    assert_eq!(version.id, "OihdIimA");
+    assert_eq!(version.loaders, [Loader::Fabric]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_project_version_not_found() -> modrinth_api::Result<()> {
+    let (server, api) = common::mock_api().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/project/AANobbMI/version/1.0%2Fbeta"))
+        .respond_with(ResponseTemplate::new(404))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    // The version number is escaped instead of being treated as a path
+    let version = api.get_project_version("AANobbMI", "1.0/beta").await?;
+    assert!(version.is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_project_version_errors() {
+    let (server, api) = common::mock_api().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&server)
+        .await;
+
+    assert!(matches!(
+        api.get_project_version("AANobbMI", "1.0").await,
+        Err(modrinth_api::Error::ReqwestError(_))
+    ));
+    assert!(matches!(
+        api.get_project_version("AANobbMI", "").await,
+        Err(modrinth_api::Error::InvalidIDorSlug)
+    ));
+}
+
+#[tokio::test]
+async fn get_project_versions_sends_filters() -> modrinth_api::Result<()> {
+    let (server, api) = common::mock_api().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/project/AANobbMI/version"))
+        .and(query_param("loaders", r#"["fabric","quilt"]"#))
+        .and(query_param("game_versions", r#"["1.20.1"]"#))
+        .and(query_param("featured", "false"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(format!("[{VERSION_FIXTURE}]")))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2/project/AANobbMI/version"))
+        .and(query_param_is_missing("loaders"))
+        .and(query_param_is_missing("game_versions"))
+        .and(query_param_is_missing("featured"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("[]"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let options = ProjectVersionsFilter {
+        loaders: Some(&[Loader::Fabric, Loader::Quilt]),
         game_versions: Some(&["1.20.1"]),
-        featured: Some(true),
+        featured: Some(false),
     };
-    let result = api.get_project_version("AANobbMI", Some(options)).await;
+    let versions = api.get_project_versions("AANobbMI", Some(options)).await?;
+    assert_eq!(versions.len(), 1);
+
+    let versions = api.get_project_versions("AANobbMI", None).await?;
+    assert!(versions.is_empty());
+    Ok(())
+}
 
-    assert!(result.is_err());
+#[test]
+fn base_url_must_be_a_base() {
+    assert!(matches!(
+        ModrinthAPI::default().with_base_url("mailto:api@example.com".parse().unwrap()),
+        Err(modrinth_api::Error::ParseError(_))
+    ));
+}
+
+#[test]
+fn deserialize_version_with_unknown_values() -> modrinth_api::Result<()> {
+    let version: Version = serde_json::from_str(
+        r#"{
+            "name": "Sodium 0.5.13",
+            "version_number": "mc1.20.1-0.5.13-fabric",
+            "changelog": null,
+            "dependencies": [{ "version_id": null, "project_id": "P7dR8mSH", "file_name": null, "dependency_type": "bundled" }],
+            "game_versions": ["1.20.1"],
+            "version_type": "nightly",
+            "loaders": ["fabric"],
+            "featured": true,
+            "status": "hidden",
+            "requested_status": null,
+            "id": "OihdIimA",
+            "project_id": "AANobbMI",
+            "author_id": "DzLrfrbK",
+            "date_published": "2024-10-01T00:00:00Z",
+            "downloads": 1,
+            "files": [{
+                "hashes": { "sha512": "309ecc489c12d6eb4cc40f50c902f2b4d0ed77ee511a7c7a9bcd3ca86d4cd86f989dd35bc5ff499670da34255b45b0cfd830e81f605dcf7dc5542e93ae9cd76f", "sha1": "2AAE6C35C94FCFB415DBE95F408B9CE91EE846ED" },
+                "url": "https://cdn.modrinth.com/data/AANobbMI/versions/OihdIimA/sodium.jar.asc",
+                "filename": "sodium.jar.asc",
+                "primary": false,
+                "size": 1,
+                "file_type": "signature"
+            }]
+        }"#,
+    )?;
+
+    assert_eq!(version.version_type, VersionType::Other("nightly".into()));
+    assert_eq!(version.status, Some(Status::Other("hidden".into())));
+    assert_eq!(
+        version.dependencies[0].dependency_type,
+        DependencyType::Other("bundled".into())
+    );
+    assert_eq!(
+        version.files[0].file_type,
+        Some(FileType::Other("signature".into()))
+    );
+    // Unknown values are written back unchanged
+    assert_eq!(
+        serde_json::to_string(&version.version_type)?,
+        r#""nightly""#
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn hash_reader_matches_known_digests() -> modrinth_api::Result<()> {
+    let hash = hash_reader(&b"hello world"[..]).await?;
+
+    assert_eq!(
+        hash.sha1,
+        "2aae6c35c94fcfb415dbe95f408b9ce91ee846ed".parse::<Sha1>()?
+    );
+    assert_eq!(
+        hash.sha512.as_str(),
+        "309ecc489c12d6eb4cc40f50c902f2b4d0ed77ee511a7c7a9bcd3ca86d4cd86f989dd35bc5ff499670da34255b45b0cfd830e81f605dcf7dc5542e93ae9cd76f"
+    );
     Ok(())
 }
+
+#[test]
+fn hashes_are_validated() {
+    // Uppercase is accepted and normalised
+    assert_eq!(
+        "2AAE6C35C94FCFB415DBE95F408B9CE91EE846ED"
+            .parse::<Sha1>()
+            .unwrap()
+            .as_str(),
+        "2aae6c35c94fcfb415dbe95f408b9ce91ee846ed"
+    );
+    assert!(matches!(
+        "2aae6c35".parse::<Sha1>(),
+        Err(modrinth_api::Error::InvalidSHA1)
+    ));
+    assert!(matches!(
+        "z".repeat(128).parse::<Sha512>(),
+        Err(modrinth_api::Error::InvalidSHA512)
+    ));
+}